@@ -22,7 +22,7 @@ fn part_1_inner(mut left: Vec<usize>, mut right: Vec<usize>) -> usize {
     right.sort();
     left.into_iter()
         .zip(right)
-        .map(|(l, r)| if l >= r { l - r } else { r - l })
+        .map(|(l, r)| l.abs_diff(r))
         .sum()
 }
 
@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use coord_2d::Coord2D;
-use grid::Grid;
+use grid::{Connectivity, Grid};
 use utils::AocBufReader;
 
 fn main() {
@@ -61,11 +61,8 @@ impl TopographicMap {
         }
 
         let start_val = self.grid.get(start).unwrap();
-        let neighbor_vals: HashMap<Coord2D<usize>, isize> = start
-            .cardinal_neighbors()
-            .into_iter()
-            .filter_map(|neighbor| self.grid.get(&neighbor).map(|val| (neighbor, val)))
-            .collect();
+        let neighbor_vals: HashMap<Coord2D<usize>, isize> =
+            self.grid.neighbors(start, Connectivity::Four).collect();
 
         if start_val == 8 {
             return neighbor_vals
@@ -85,7 +82,7 @@ impl TopographicMap {
             result.extend(self.reachable_peaks(&reachable_neighbor).clone());
         }
 
-        self.cache_part_1.insert(start.clone(), result.clone());
+        self.cache_part_1.insert(*start, result.clone());
         result
     }
 
@@ -95,11 +92,8 @@ impl TopographicMap {
         }
 
         let start_val = self.grid.get(start).unwrap();
-        let neighbor_vals: HashMap<Coord2D<usize>, isize> = start
-            .cardinal_neighbors()
-            .into_iter()
-            .filter_map(|neighbor| self.grid.get(&neighbor).map(|val| (neighbor, val)))
-            .collect();
+        let neighbor_vals: HashMap<Coord2D<usize>, isize> =
+            self.grid.neighbors(start, Connectivity::Four).collect();
 
         if start_val == 8 {
             return neighbor_vals
@@ -119,7 +113,7 @@ impl TopographicMap {
             result += n_paths;
         }
 
-        self.cache_part_2.insert(start.clone(), result);
+        self.cache_part_2.insert(*start, result);
         result
     }
 }
@@ -69,7 +69,7 @@ impl RockCollection {
 fn maybe_split(x: usize) -> Option<(usize, usize)> {
     let s = x.to_string();
     let n_digits = s.len();
-    if s.len() % 2 == 0 {
+    if s.len().is_multiple_of(2) {
         Some((
             s[..(n_digits / 2)].parse::<usize>().unwrap(),
             s[(n_digits / 2)..].parse::<usize>().unwrap(),
@@ -5,6 +5,8 @@ use std::{collections::HashMap, io::Write};
 use regex::Regex;
 
 use coord_2d::Coord2D;
+use grid::{Grid, Renderer};
+use span_1d::{Rect, Span1D};
 use utils::{parse_iter, AocBufReader};
 
 fn main() {
@@ -57,63 +59,44 @@ impl Map {
     }
 
     fn safety_factor(&self) -> usize {
-        let mut quadrant_1_ct: usize = 0;
-        let mut quadrant_2_ct: usize = 0;
-        let mut quadrant_3_ct: usize = 0;
-        let mut quadrant_4_ct: usize = 0;
-
-        for robot in self.robots.iter() {
-            if robot.position.col == self.n_x / 2 || robot.position.row == self.n_y / 2 {
-                continue;
-            }
-
-            match (
-                robot.position.col > self.n_x / 2,
-                robot.position.row > self.n_y / 2,
-            ) {
-                (true, true) => quadrant_1_ct += 1,
-                (true, false) => quadrant_2_ct += 1,
-                (false, true) => quadrant_3_ct += 1,
-                (false, false) => quadrant_4_ct += 1,
-            }
-        }
-
-        quadrant_1_ct * quadrant_2_ct * quadrant_3_ct * quadrant_4_ct
+        let floor = Rect::new(Span1D::new(0, self.n_y), Span1D::new(0, self.n_x));
+        floor
+            .quadrants()
+            .iter()
+            .map(|quadrant| {
+                self.robots
+                    .iter()
+                    .filter(|robot| quadrant.contains(&robot.position))
+                    .count()
+            })
+            .product()
     }
 
-    fn as_string(&self) -> String {
-        let mut robot_position_cts: HashMap<&Coord2D<isize>, usize> = HashMap::new();
+    fn write_to(&self, out: &mut impl Write) {
+        let mut robot_position_cts: HashMap<Coord2D<usize>, usize> = HashMap::new();
         for robot in self.robots.iter() {
-            *robot_position_cts.entry(&robot.position).or_insert(0) += 1;
+            let position = Coord2D::new(robot.position.row as usize, robot.position.col as usize);
+            *robot_position_cts.entry(position).or_insert(0) += 1;
         }
 
-        let mut rows: Vec<String> = vec![];
-        for row_idx in 0..self.n_y {
-            let row = (0..self.n_x)
-                .map(
-                    |col_idx| match robot_position_cts.get(&Coord2D::new(row_idx, col_idx)) {
-                        Some(val) => format!("{}", val),
-                        None => ".".to_string(),
-                    },
-                )
-                .collect::<String>();
-            rows.push(row);
-        }
-
-        rows.join("\n")
+        let floor = Grid::new(vec![vec!['.'; self.n_x as usize]; self.n_y as usize]);
+        let rendered = Renderer::new(&floor)
+            .overlay_with(robot_position_cts, None)
+            .render();
+        // the renderer ends every row with a newline; frames don't end with one
+        out.write_all(rendered.trim_end_matches('\n').as_bytes())
+            .unwrap();
     }
 
     #[allow(dead_code)]
     fn print(&self) {
         println!("\n");
-        std::io::stdout()
-            .write_all(self.as_string().as_bytes())
-            .unwrap();
+        self.write_to(&mut std::io::stdout());
         println!("\n");
     }
 
     fn print_to_file(&self, f: &mut std::fs::File) {
-        f.write_all(self.as_string().as_bytes()).unwrap();
+        self.write_to(f);
     }
 }
 
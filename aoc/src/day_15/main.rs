@@ -1,7 +1,8 @@
+use std::cmp::Reverse;
 use std::collections::HashSet;
 
-use coord_2d::Coord2D;
-use direction::CardinalDirection;
+use coord_2d::{ColMajor, Coord2D};
+use direction::{CardinalDirection, DirectionAlphabet};
 use grid::Grid;
 use itertools::Itertools;
 use utils::AocBufReader;
@@ -45,7 +46,7 @@ impl Warehouse {
     }
 
     fn move_robot_part_1(&mut self, direction: CardinalDirection) {
-        let robot_position = self.robot_position.clone();
+        let robot_position = self.robot_position;
         let robot_dest = robot_position.adjacent(&direction).unwrap();
         let robot_dest_obj = self.map.get(&robot_dest).unwrap();
 
@@ -60,7 +61,7 @@ impl Warehouse {
             }
             'O' => {
                 // let's try to push some boxes!
-                let mut first_non_box_location = robot_dest.clone();
+                let mut first_non_box_location = robot_dest;
                 let first_non_box: char = {
                     loop {
                         first_non_box_location =
@@ -94,7 +95,7 @@ impl Warehouse {
     }
 
     fn move_robot_part_2(&mut self, direction: CardinalDirection) {
-        let robot_position = self.robot_position.clone();
+        let robot_position = self.robot_position;
         let robot_dest = robot_position.adjacent(&direction).unwrap();
         let robot_dest_obj = self.map.get(&robot_dest).unwrap();
 
@@ -135,7 +136,7 @@ impl Warehouse {
         // we'll just store their left edge for simplicity
         match robot_dest_obj {
             '[' => {
-                boxes_to_check.push(robot_dest.clone());
+                boxes_to_check.push(robot_dest);
             }
             ']' => {
                 boxes_to_check.push(robot_dest.west().unwrap());
@@ -195,17 +196,15 @@ impl Warehouse {
         }
 
         let boxes_to_move: Vec<Coord2D<usize>> = match direction {
-            CardinalDirection::North => boxes_to_move
+            CardinalDirection::North => boxes_to_move.into_iter().sorted().rev().collect(),
+            CardinalDirection::East => boxes_to_move
                 .into_iter()
-                .sorted_by_key(|x| usize::MAX - x.row)
+                .sorted_by_key(|x| ColMajor(*x))
                 .collect(),
-            CardinalDirection::East => boxes_to_move.into_iter().sorted_by_key(|x| x.col).collect(),
-            CardinalDirection::South => {
-                boxes_to_move.into_iter().sorted_by_key(|x| x.row).collect()
-            }
+            CardinalDirection::South => boxes_to_move.into_iter().sorted().collect(),
             CardinalDirection::West => boxes_to_move
                 .into_iter()
-                .sorted_by_key(|x| usize::MAX - x.col)
+                .sorted_by_key(|x| Reverse(ColMajor(*x)))
                 .collect(),
         };
 
@@ -225,21 +224,21 @@ impl Warehouse {
     ) {
         let mut boxes_to_move: Vec<Coord2D<usize>> = Vec::new();
         let mut current_box = match robot_dest_obj {
-            '[' => robot_dest.clone(),
+            '[' => robot_dest,
             ']' => robot_dest.west().unwrap(),
             _ => panic!("I thought we were pushing boxes"),
         };
 
         let mut we_can_move_boxes: bool = true;
         loop {
-            boxes_to_move.push(current_box.clone());
+            boxes_to_move.push(current_box);
             let next_box = current_box
                 .adjacent(&direction)
                 .unwrap()
                 .adjacent(&direction)
                 .unwrap();
             let neighbor = match direction {
-                CardinalDirection::East => next_box.clone(),
+                CardinalDirection::East => next_box,
                 CardinalDirection::West => current_box.west().unwrap(),
                 _ => panic!(),
             };
@@ -326,7 +325,7 @@ impl Warehouse {
 
         let mut directions: Vec<CardinalDirection> = Vec::new();
         for line in input {
-            directions.extend(line.chars().map(CardinalDirection::from_char))
+            directions.extend(DirectionAlphabet::Arrows.parse_instructions(&line).unwrap())
         }
 
         let robot_positions = grid.find('@');
@@ -365,7 +364,7 @@ impl Warehouse {
 
         let mut directions: Vec<CardinalDirection> = Vec::new();
         for line in input {
-            directions.extend(line.chars().map(CardinalDirection::from_char))
+            directions.extend(DirectionAlphabet::Arrows.parse_instructions(&line).unwrap())
         }
 
         let robot_positions = grid.find('@');
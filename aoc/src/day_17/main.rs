@@ -34,6 +34,7 @@ fn part_2(input: AocBufReader) {
 ///     2,4,1,2,7,5,4,7,1,3,5,5,0,3,3,0
 ///
 /// This has a few steps:
+/// ```text
 ///   1) 2(4) - (Bst, 4) - place last 3 bits from A into B
 ///   2) 1(2) - (Bxl, 2) - XOR the value in B with (...010)
 ///   3) 7(5) - (Cdv, 5) - interpret register B as a number, n;
@@ -46,6 +47,7 @@ fn part_2(input: AocBufReader) {
 ///                        significant bits.
 ///   8) 3(0) - (Jnz, 0) - If A is not zero, return to the start
 ///                        of the program; if A is zero, halt
+/// ```
 ///
 /// From the structure of this program, we know that the length
 /// of the intial value of A (in bits) is equal to the length of
@@ -1,10 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use itertools::Itertools;
 use rayon::prelude::*;
 
 use coord_2d::Coord2D;
-use grid::Grid;
+use grid::{Connectivity, Grid};
 use utils::AocBufReader;
 
 fn main() {
@@ -39,39 +38,30 @@ fn inner(map: Map, cheat_len: usize) -> usize {
     let open_coords: Vec<_> = map.grid.find('.').into_iter().collect();
 
     // build a list of (S, cheat_start) pairs from S to every other coordinate
-    let mut src_dest_pairs: Vec<_> = open_coords
-        .iter()
-        .map(|e| (map.start.clone(), e.clone()))
-        .collect();
+    let mut src_dest_pairs: Vec<_> = open_coords.iter().map(|e| (map.start, *e)).collect();
     // extend to list with pairs (cheat_end, E) from every cheat end to E
-    src_dest_pairs.extend(open_coords.iter().map(|s| (s.clone(), map.end.clone())));
+    src_dest_pairs.extend(open_coords.iter().map(|s| (*s, map.end)));
 
     // sort these by the distance separating them so that longer paths that include shorter
     // paths within them can benefit from caching
-    src_dest_pairs.sort_by_key(|(start, end)| {
-        (start.row as isize - end.row as isize).abs()
-            + (start.col as isize - end.col as isize).abs()
-    });
+    src_dest_pairs.sort_by_key(|(start, end)| start.manhattan_distance(end));
 
     // build up a cache precomputing each of the distances described above.
     let mut cache: Cache = HashMap::new();
     for src_dest in src_dest_pairs {
         let mut visited: HashSet<Coord2D<usize>> = HashSet::new();
         let result = shortest_path(&map, &src_dest, &mut visited, &cache);
-        cache.insert(src_dest.clone(), result);
+        cache.insert(src_dest, result);
     }
 
-    let original_length = cache
-        .get(&(map.start.clone(), map.end.clone()))
-        .unwrap()
-        .unwrap();
+    let original_length = cache.get(&(map.start, map.end)).unwrap().unwrap();
     let cheat_start_ends = map.cheats(cheat_len);
     cheat_start_ends
         .par_iter()
         .filter(|(cheat_start, cheat_end)| {
             if let (Some(Some(start_to_cheat)), Some(Some(cheat_to_end))) = (
-                cache.get(&(map.start.clone(), cheat_start.clone())),
-                cache.get(&(cheat_end.clone(), map.end.clone())),
+                cache.get(&(map.start, *cheat_start)),
+                cache.get(&(*cheat_end, map.end)),
             ) {
                 let length_w_cheat =
                     start_to_cheat + cheat_start.manhattan_distance(cheat_end) + cheat_to_end;
@@ -99,21 +89,17 @@ fn shortest_path(
         return *cached;
     }
 
-    visited.insert(start.clone());
+    visited.insert(*start);
 
-    let next: Vec<_> = start
-        .cardinal_neighbors()
-        .into_iter()
-        .filter(|x| {
-            !visited.contains(x)
-                && x.row < map.grid.n_rows
-                && x.col < map.grid.n_cols
-                && map.grid.get(x).unwrap() != '#'
-        })
+    let next: Vec<_> = map
+        .grid
+        .neighbors(start, Connectivity::Four)
+        .filter(|(x, c)| !visited.contains(x) && *c != '#')
+        .map(|(x, _)| x)
         .collect();
 
     next.into_iter()
-        .filter_map(|x| shortest_path(map, &(x.clone(), end.clone()), visited, cache))
+        .filter_map(|x| shortest_path(map, &(x, *end), visited, cache))
         .min()
         .map(|x| x + 1)
 }
@@ -128,24 +114,14 @@ impl Map {
     /// Return every pair of coords separated (manhattan distance)
     /// by up to len that are both open spaces
     fn cheats(&self, len: usize) -> Vec<(Coord2D<usize>, Coord2D<usize>)> {
-        let open_spaces: Vec<_> = self.grid.find('.').into_iter().collect();
-        let n_open_spaces = open_spaces.len();
-        (0..n_open_spaces)
-            .cartesian_product(0..n_open_spaces)
-            .filter(|(first_idx, second_idx)| {
-                if first_idx == second_idx {
-                    false
-                } else {
-                    let first = &open_spaces[*first_idx];
-                    let second = &open_spaces[*second_idx];
-                    first.manhattan_distance(second) <= len
-                }
-            })
-            .map(|(first_idx, second_idx)| {
-                (
-                    open_spaces[first_idx].clone(),
-                    open_spaces[second_idx].clone(),
-                )
+        self.grid
+            .find('.')
+            .into_iter()
+            .flat_map(|first| {
+                first
+                    .within_manhattan(len)
+                    .filter(move |second| *second != first && self.grid.get(second) == Some('.'))
+                    .map(move |second| (first, second))
             })
             .collect()
     }
@@ -5,7 +5,7 @@ use rayon::prelude::*;
 
 use coord_2d::Coord2D;
 use direction::CardinalDirection;
-use grid::Grid;
+use grid::{BitGrid, Grid};
 use utils::AocBufReader;
 
 fn main() {
@@ -34,8 +34,8 @@ fn part_1_inner(grid: Grid<char>) -> usize {
 fn part_2_inner(grid: Grid<char>) -> usize {
     let map = Map::new(grid);
 
-    let row_cols: Vec<(usize, usize)> = (0..map.grid.n_rows)
-        .cartesian_product(0..map.grid.n_cols)
+    let row_cols: Vec<(usize, usize)> = (0..map.walls.n_rows)
+        .cartesian_product(0..map.walls.n_cols)
         .filter(|(r, c)| map.position != Coord2D::new(*r, *c))
         .collect();
 
@@ -43,7 +43,7 @@ fn part_2_inner(grid: Grid<char>) -> usize {
         .par_iter()
         .map(|(row_idx, col_idx)| {
             let mut new_map = map.clone();
-            new_map.grid.set('#', *row_idx, *col_idx);
+            new_map.walls.insert(&Coord2D::new(*row_idx, *col_idx));
             run_to_completion(new_map)
         })
         .filter(|x| match x {
@@ -59,50 +59,47 @@ enum CompletionCondition {
 }
 
 fn run_to_completion(mut map: Map) -> CompletionCondition {
-    let mut positions: HashSet<Coord2D<usize>> = HashSet::new();
+    let mut positions = BitGrid::new(map.walls.n_rows, map.walls.n_cols);
     let mut state: HashSet<(Coord2D<usize>, CardinalDirection)> = HashSet::new();
-    positions.insert(map.position.clone());
+    positions.insert(&map.position);
     while let Some(next) = map.next() {
-        if state.contains(&(next.clone(), map.direction)) {
+        if state.contains(&(next, map.direction)) {
             return CompletionCondition::Looped;
         } else {
-            state.insert((map.position.clone(), map.direction));
-            positions.insert(next);
+            state.insert((map.position, map.direction));
+            positions.insert(&next);
         }
     }
 
-    CompletionCondition::WalkedOffTheMap(positions.len())
+    CompletionCondition::WalkedOffTheMap(positions.count_ones())
 }
 
 #[derive(Clone)]
 struct Map {
     position: Coord2D<usize>,
     direction: CardinalDirection,
-    grid: Grid<char>,
+    walls: BitGrid,
 }
 
 impl Map {
     fn next(&mut self) -> Option<Coord2D<usize>> {
         match self.position.adjacent(&self.direction) {
             None => None,
-            Some(forward) => match self.grid.get(&forward) {
-                Some(c) => match c {
-                    '#' => {
-                        self.direction = self.direction.turn_right();
-                        Some(self.position.clone())
-                    }
-                    '.' => {
-                        self.position = forward.clone();
-                        Some(forward)
-                    }
-                    _ => panic!("I think I saw a 2! {}", c),
-                },
+            Some(forward) => match self.walls.get(&forward) {
+                Some(true) => {
+                    self.direction = self.direction.turn_right();
+                    Some(self.position)
+                }
+                Some(false) => {
+                    self.position = forward;
+                    Some(forward)
+                }
                 None => None,
             },
         }
     }
 
-    fn new(mut grid: Grid<char>) -> Self {
+    fn new(grid: Grid<char>) -> Self {
         let mut position: Option<Coord2D<usize>> = None;
         let mut direction: Option<CardinalDirection> = None;
 
@@ -119,14 +116,15 @@ impl Map {
         }
 
         match (position, direction) {
-            (Some(p), Some(d)) => {
-                grid.set('.', p.row, p.col);
-                Self {
-                    position: p,
-                    direction: d,
-                    grid,
-                }
-            }
+            (Some(p), Some(d)) => Self {
+                position: p,
+                direction: d,
+                walls: BitGrid::from_grid(&grid, |c| match c {
+                    '#' => true,
+                    '.' | '<' | '^' | '>' | 'v' => false,
+                    _ => panic!("I think I saw a 2! {}", c),
+                }),
+            },
             _ => panic!("We didn't find the guard!"),
         }
     }
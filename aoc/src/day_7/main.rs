@@ -91,7 +91,7 @@ fn is_solvable(val: usize, args: &[usize], operations: &[BinaryOperation]) -> bo
     // we assume the last operation is multiplication and recurse
     let is_solvable_by_multiplication: bool = {
         operations.contains(&BinaryOperation::Multiplication)
-            && val % last == 0
+            && val.is_multiple_of(last)
             && is_solvable(val / last, &args[..(n_args - 1)], operations)
     };
 
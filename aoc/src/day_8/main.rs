@@ -19,92 +19,114 @@ fn part_2(input: AocBufReader) {
     println!("{}", part_2_inner(grid))
 }
 
-fn part_1_inner(grid: Grid<char>) -> usize {
-    let mut char_positions: HashMap<char, Vec<Coord2D<isize>>> = HashMap::new();
-    for (coord, c) in grid.coords_and_vals::<isize>().filter(|&(_, c)| c != '.') {
+fn antennae_by_frequency(grid: &Grid<char>) -> HashMap<char, Vec<Coord2D<usize>>> {
+    let mut char_positions: HashMap<char, Vec<Coord2D<usize>>> = HashMap::new();
+    for (coord, c) in grid.coords_and_vals::<usize>().filter(|&(_, c)| c != '.') {
         char_positions.entry(c).or_default().push(coord);
     }
-
     char_positions
-        .into_iter()
-        .flat_map(|(_, antennae_loc)| {
-            let mut nodes: Vec<Coord2D<isize>> = Vec::new();
+}
+
+/// The vector pointing from second to first
+fn separation(first: &Coord2D<usize>, second: &Coord2D<usize>) -> Coord2D<isize> {
+    let signed = |coord: &Coord2D<usize>| Coord2D::<isize>::try_from(*coord).unwrap();
+    signed(first) - signed(second)
+}
+
+fn part_1_inner(grid: Grid<char>) -> usize {
+    antennae_by_frequency(&grid)
+        .into_values()
+        .flat_map(|antennae_loc| {
+            let mut nodes: Vec<Coord2D<usize>> = Vec::new();
             let n_locations = antennae_loc.len();
             for first_idx in 0..n_locations {
                 for second_idx in (first_idx + 1)..n_locations {
                     let first = &antennae_loc[first_idx];
                     let second = &antennae_loc[second_idx];
-                    let v = first.clone() - second.clone();
-
-                    let candidate_1 = first.clone() + v.clone();
-                    let candidate_2 = second.clone() - v;
-                    if candidate_1.is_nonnegative() && grid.get(&candidate_1).is_some() {
-                        nodes.push(candidate_1);
-                    }
+                    let v = separation(first, second);
 
-                    if candidate_2.is_nonnegative() && grid.get(&candidate_2).is_some() {
-                        nodes.push(candidate_2);
-                    }
+                    nodes.extend(grid.ray(first, v).take(1).map(|(c, _)| c));
+                    nodes.extend(grid.ray(second, -v).take(1).map(|(c, _)| c));
                 }
             }
             nodes
         })
-        .collect::<HashSet<Coord2D<isize>>>()
+        .collect::<HashSet<Coord2D<usize>>>()
         .len()
 }
 
 fn part_2_inner(grid: Grid<char>) -> usize {
-    let mut char_positions: HashMap<char, Vec<Coord2D<isize>>> = HashMap::new();
-    for (coord, c) in grid.coords_and_vals::<isize>().filter(|&(_, c)| c != '.') {
-        char_positions.entry(c).or_default().push(coord);
-    }
-
-    char_positions
-        .into_iter()
-        .flat_map(|(_, antennae_loc)| {
-            let mut nodes: Vec<Coord2D<isize>> = Vec::new();
+    antennae_by_frequency(&grid)
+        .into_values()
+        .flat_map(|antennae_loc| {
+            let mut nodes: Vec<Coord2D<usize>> = Vec::new();
             let n_locations = antennae_loc.len();
             for first_idx in 0..n_locations {
                 for second_idx in (first_idx + 1)..n_locations {
                     let first = &antennae_loc[first_idx];
                     let second = &antennae_loc[second_idx];
-                    let v = first.clone() - second.clone();
+                    // step by the reduced vector so that every lattice point in
+                    // line is an antinode, including any between the antennas
+                    let v = separation(first, second).reduced();
 
-                    let mut harmonic = 0isize;
-                    loop {
-                        let candidate = first.clone() + v.clone().mul_scalar(harmonic);
-                        if candidate.row < 0
-                            || candidate.col < 0
-                            || candidate.row >= grid.n_rows as isize
-                            || candidate.col >= grid.n_cols as isize
-                        {
-                            break;
-                        }
-                        if grid.get(&candidate).is_some() {
-                            nodes.push(candidate);
-                        }
-                        harmonic += 1;
-                    }
-
-                    let mut harmonic = 0isize;
-                    loop {
-                        let candidate = second.clone() - v.clone().mul_scalar(harmonic);
-                        if candidate.row < 0
-                            || candidate.col < 0
-                            || candidate.row >= grid.n_rows as isize
-                            || candidate.col >= grid.n_cols as isize
-                        {
-                            break;
-                        }
-                        if grid.get(&candidate).is_some() {
-                            nodes.push(candidate);
-                        }
-                        harmonic += 1;
-                    }
+                    // every antenna is itself an antinode (the zeroth harmonic);
+                    // the ray from each antenna passes through the other one
+                    nodes.push(*first);
+                    nodes.extend(grid.ray(first, -v).map(|(c, _)| c));
+                    nodes.push(*second);
+                    nodes.extend(grid.ray(second, v).map(|(c, _)| c));
                 }
             }
             nodes
         })
-        .collect::<HashSet<Coord2D<isize>>>()
+        .collect::<HashSet<Coord2D<usize>>>()
         .len()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::from_line_iter(
+            [
+                "............",
+                "........0...",
+                ".....0......",
+                ".......0....",
+                "....0.......",
+                "......A.....",
+                "............",
+                "............",
+                "........A...",
+                ".........A..",
+                "............",
+                "............",
+            ]
+            .into_iter()
+            .map(|x| x.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1_inner(example()), 14)
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2_inner(example()), 34)
+    }
+
+    #[test]
+    fn test_part_2_between_antennas() {
+        // the antennas are two steps apart along the diagonal, so the
+        // cells between and beyond them are in line too
+        let grid = Grid::from_line_iter(
+            ["a....", ".....", "..a..", ".....", "....."]
+                .into_iter()
+                .map(|x| x.to_string()),
+        );
+        assert_eq!(part_2_inner(grid), 5)
+    }
+}
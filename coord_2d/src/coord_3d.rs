@@ -0,0 +1,175 @@
+use std::hash::Hash;
+use std::ops;
+
+use num::traits::{CheckedAdd, CheckedSub, SaturatingAdd, SaturatingSub};
+use num::{Integer, Signed};
+
+use crate::coord_n::CoordN;
+
+/// A coordinate in three dimensions; shares its implementation with `CoordN<T, 3>`
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Copy)]
+pub struct Coord3D<T: Integer + PartialOrd + Eq + Hash + Copy> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> From<CoordN<T, 3>> for Coord3D<T> {
+    fn from(coord: CoordN<T, 3>) -> Self {
+        let [x, y, z] = coord.0;
+        Self { x, y, z }
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> From<Coord3D<T>> for CoordN<T, 3> {
+    fn from(coord: Coord3D<T>) -> Self {
+        CoordN([coord.x, coord.y, coord.z])
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> Coord3D<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    fn as_n(&self) -> CoordN<T, 3> {
+        CoordN::from(*self)
+    }
+
+    pub fn mul_scalar(&self, v: T) -> Self {
+        self.as_n().mul_scalar(v).into()
+    }
+
+    pub fn is_nonnegative(&self) -> bool {
+        self.as_n().is_nonnegative()
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        self.as_n().manhattan_distance(&other.as_n())
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        self.as_n().chebyshev_distance(&other.as_n())
+    }
+
+    pub fn squared_euclidean_distance(&self, other: &Self) -> T {
+        self.as_n().squared_euclidean_distance(&other.as_n())
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy + CheckedAdd + CheckedSub> Coord3D<T> {
+    /// The 26 surrounding cubes, skipping any that T can't represent
+    pub fn neighbors(&self) -> Vec<Self> {
+        self.as_n()
+            .neighbors()
+            .into_iter()
+            .map(Self::from)
+            .collect()
+    }
+
+    /// The 6 cubes sharing a face with this one, skipping any that T can't represent
+    pub fn cardinal_neighbors(&self) -> Vec<Self> {
+        self.as_n()
+            .cardinal_neighbors()
+            .into_iter()
+            .map(Self::from)
+            .collect()
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.as_n().checked_add(&other.as_n()).map(Self::from)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.as_n().checked_sub(&other.as_n()).map(Self::from)
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy + SaturatingAdd + SaturatingSub> Coord3D<T> {
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        self.as_n().saturating_add(&other.as_n()).into()
+    }
+
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        self.as_n().saturating_sub(&other.as_n()).into()
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> ops::Add for Coord3D<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        (self.as_n() + other.as_n()).into()
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> ops::Sub for Coord3D<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        (self.as_n() - other.as_n()).into()
+    }
+}
+
+impl<T: Integer + Signed + PartialOrd + Eq + Hash + Copy> ops::Neg for Coord3D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        (-self.as_n()).into()
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> ops::Mul<T> for Coord3D<T> {
+    type Output = Self;
+
+    fn mul(self, v: T) -> Self {
+        self.mul_scalar(v)
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> ops::Div<T> for Coord3D<T> {
+    type Output = Self;
+
+    fn div(self, v: T) -> Self {
+        (self.as_n() / v).into()
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> ops::AddAssign for Coord3D<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> ops::SubAssign for Coord3D<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> ops::MulAssign<T> for Coord3D<T> {
+    fn mul_assign(&mut self, v: T) {
+        *self = *self * v;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coord_3d() {
+        let a: Coord3D<isize> = Coord3D::new(1, 2, 3);
+        let b: Coord3D<isize> = Coord3D::new(-1, 2, 7);
+        assert_eq!(a + b, Coord3D::new(0, 4, 10));
+        assert_eq!(a - b, Coord3D::new(2, 0, -4));
+        assert_eq!(-a, Coord3D::new(-1, -2, -3));
+        assert_eq!(a.manhattan_distance(&b), 6);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.squared_euclidean_distance(&b), 20);
+
+        assert_eq!(a.neighbors().len(), 26);
+        assert_eq!(a.cardinal_neighbors().len(), 6);
+        assert_eq!(Coord3D::<u32>::new(0, 0, 0).neighbors().len(), 7);
+    }
+}
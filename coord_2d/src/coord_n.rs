@@ -0,0 +1,240 @@
+use std::hash::Hash;
+use std::ops;
+
+use num::traits::{CheckedAdd, CheckedSub, SaturatingAdd, SaturatingSub};
+use num::{Integer, Signed};
+
+use crate::difference;
+
+/// A coordinate with any number of dimensions
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Copy)]
+pub struct CoordN<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize>(pub [T; D]);
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize> CoordN<T, D> {
+    pub fn new(components: [T; D]) -> Self {
+        Self(components)
+    }
+
+    pub fn origin() -> Self {
+        Self([T::zero(); D])
+    }
+
+    pub fn mul_scalar(&self, v: T) -> Self {
+        Self(self.0.map(|x| x * v))
+    }
+
+    pub fn is_nonnegative(&self) -> bool {
+        self.0.iter().all(|x| *x >= T::zero())
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        (0..D).fold(T::zero(), |acc, axis| {
+            acc + difference(self.0[axis], other.0[axis])
+        })
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        (0..D).fold(T::zero(), |acc, axis| {
+            std::cmp::max(acc, difference(self.0[axis], other.0[axis]))
+        })
+    }
+
+    pub fn squared_euclidean_distance(&self, other: &Self) -> T {
+        (0..D).fold(T::zero(), |acc, axis| {
+            let d = difference(self.0[axis], other.0[axis]);
+            acc + d * d
+        })
+    }
+
+    /// Combine two coords axis by axis
+    fn zip_with(&self, other: &Self, f: impl Fn(T, T) -> T) -> Self {
+        Self(std::array::from_fn(|axis| f(self.0[axis], other.0[axis])))
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy + CheckedAdd + CheckedSub, const D: usize>
+    CoordN<T, D>
+{
+    /// Every coord that differs by at most one along each axis (3^D - 1 of them),
+    /// skipping any that T can't represent (e.g. negative unsigned coords)
+    pub fn neighbors(&self) -> Vec<Self> {
+        let mut result: Vec<Self> = vec![*self];
+        for axis in 0..D {
+            result = result
+                .into_iter()
+                .flat_map(|coord| {
+                    [
+                        coord.0[axis].checked_sub(&T::one()),
+                        Some(coord.0[axis]),
+                        coord.0[axis].checked_add(&T::one()),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .map(move |x| {
+                        let mut neighbor = coord;
+                        neighbor.0[axis] = x;
+                        neighbor
+                    })
+                })
+                .collect();
+        }
+        result.retain(|neighbor| neighbor != self);
+        result
+    }
+
+    /// The coords one step away along a single axis (2D of them),
+    /// skipping any that T can't represent
+    pub fn cardinal_neighbors(&self) -> Vec<Self> {
+        let mut result: Vec<Self> = Vec::new();
+        for axis in 0..D {
+            for x in [
+                self.0[axis].checked_sub(&T::one()),
+                self.0[axis].checked_add(&T::one()),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                let mut neighbor = *self;
+                neighbor.0[axis] = x;
+                result.push(neighbor);
+            }
+        }
+        result
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let mut result = *self;
+        for axis in 0..D {
+            result.0[axis] = self.0[axis].checked_add(&other.0[axis])?;
+        }
+        Some(result)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let mut result = *self;
+        for axis in 0..D {
+            result.0[axis] = self.0[axis].checked_sub(&other.0[axis])?;
+        }
+        Some(result)
+    }
+}
+
+impl<
+        T: Integer + PartialOrd + Eq + Hash + Copy + SaturatingAdd + SaturatingSub,
+        const D: usize,
+    > CoordN<T, D>
+{
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a.saturating_add(&b))
+    }
+
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a.saturating_sub(&b))
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize> ops::Add for CoordN<T, D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.zip_with(&other, |a, b| a + b)
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize> ops::Sub for CoordN<T, D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.zip_with(&other, |a, b| a - b)
+    }
+}
+
+impl<T: Integer + Signed + PartialOrd + Eq + Hash + Copy, const D: usize> ops::Neg
+    for CoordN<T, D>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|x| -x))
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize> ops::Mul<T> for CoordN<T, D> {
+    type Output = Self;
+
+    fn mul(self, v: T) -> Self {
+        self.mul_scalar(v)
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize> ops::Div<T> for CoordN<T, D> {
+    type Output = Self;
+
+    fn div(self, v: T) -> Self {
+        Self(self.0.map(|x| x / v))
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize> ops::AddAssign for CoordN<T, D> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize> ops::SubAssign for CoordN<T, D> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy, const D: usize> ops::MulAssign<T>
+    for CoordN<T, D>
+{
+    fn mul_assign(&mut self, v: T) {
+        *self = *self * v;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbors() {
+        let x: CoordN<isize, 4> = CoordN::origin();
+        assert_eq!(x.neighbors().len(), 80);
+        assert_eq!(x.cardinal_neighbors().len(), 8);
+
+        let corner: CoordN<usize, 3> = CoordN::new([0, 0, 5]);
+        assert_eq!(corner.neighbors().len(), 11);
+        assert_eq!(
+            corner.cardinal_neighbors(),
+            vec![
+                CoordN::new([1, 0, 5]),
+                CoordN::new([0, 1, 5]),
+                CoordN::new([0, 0, 4]),
+                CoordN::new([0, 0, 6])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a: CoordN<i64, 4> = CoordN::new([1, -2, 3, 0]);
+        let b: CoordN<i64, 4> = CoordN::new([0, 2, 1, -5]);
+        assert_eq!(a + b, CoordN::new([1, 0, 4, -5]));
+        assert_eq!(a - b, CoordN::new([1, -4, 2, 5]));
+        assert_eq!(-a * 2, CoordN::new([-2, 4, -6, 0]));
+        assert_eq!(a.manhattan_distance(&b), 12);
+        assert_eq!(a.chebyshev_distance(&b), 5);
+        assert_eq!(a.squared_euclidean_distance(&b), 46);
+
+        let c: CoordN<u8, 2> = CoordN::new([1, 250]);
+        assert_eq!(c.checked_add(&CoordN::new([0, 10])), None);
+        assert_eq!(c.checked_sub(&CoordN::new([2, 0])), None);
+        assert_eq!(
+            c.saturating_add(&CoordN::new([1, 10])),
+            CoordN::new([2, 255])
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::hash::Hash;
+use std::ops;
+
+use num::{Integer, Signed};
+
+use direction::HexDirection;
+
+use crate::Coord2D;
+
+/// A pointy-topped hexagon in axial coordinates: q increases to the east
+/// and r to the south east. The implied third cube coordinate is
+/// s = -q - r, so that q + r + s == 0.
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Copy)]
+pub struct Hex<T: Integer + Signed + Eq + Hash + Copy> {
+    pub q: T,
+    pub r: T,
+}
+
+impl<T: Integer + Signed + Eq + Hash + Copy> Hex<T> {
+    pub fn new(q: T, r: T) -> Self {
+        Self { q, r }
+    }
+
+    /// None unless q + r + s == 0
+    pub fn from_cube(q: T, r: T, s: T) -> Option<Self> {
+        if (q + r + s).is_zero() {
+            Some(Self::new(q, r))
+        } else {
+            None
+        }
+    }
+
+    pub fn s(&self) -> T {
+        -self.q - self.r
+    }
+
+    /// The hex one step away in direction
+    pub fn adjacent(&self, direction: &HexDirection) -> Self {
+        *self + Self::unit(direction)
+    }
+
+    /// The six hexes sharing an edge with this one,
+    /// counterclockwise from east
+    pub fn neighbors(&self) -> Vec<Self> {
+        HexDirection::ALL
+            .iter()
+            .map(|direction| self.adjacent(direction))
+            .collect()
+    }
+
+    /// The number of steps between two hexes
+    pub fn distance(&self, other: &Self) -> T {
+        let delta = *self - *other;
+        (delta.q.abs() + delta.r.abs() + delta.s().abs()) / (T::one() + T::one())
+    }
+
+    /// Every hex at exactly radius steps away, counterclockwise from the
+    /// south west corner; a ring of radius 0 is just this hex
+    pub fn ring(&self, radius: T) -> Vec<Self> {
+        let mut result: Vec<Self> = Vec::new();
+        let mut current = *self + Self::unit(&HexDirection::SouthWest) * radius;
+        if radius.is_zero() {
+            return vec![current];
+        }
+
+        for direction in HexDirection::ALL {
+            let mut step = T::zero();
+            while step < radius {
+                result.push(current);
+                current = current.adjacent(&direction);
+                step = step + T::one();
+            }
+        }
+        result
+    }
+
+    /// Every hex within radius steps, ring by ring outward from this one
+    pub fn spiral(&self, radius: T) -> Vec<Self> {
+        let mut result: Vec<Self> = Vec::new();
+        let mut ring_radius = T::zero();
+        while ring_radius <= radius {
+            result.extend(self.ring(ring_radius));
+            ring_radius = ring_radius + T::one();
+        }
+        result
+    }
+
+    /// Rotate 60 degrees clockwise about origin
+    pub fn rotate_cw(&self, origin: &Self) -> Self {
+        let offset = *self - *origin;
+        *origin + Self::new(-offset.r, -offset.s())
+    }
+
+    /// Rotate 60 degrees counterclockwise about origin
+    pub fn rotate_ccw(&self, origin: &Self) -> Self {
+        let offset = *self - *origin;
+        *origin + Self::new(-offset.s(), -offset.q)
+    }
+
+    /// The (row, col) of this hex in an "odd-r" layout, where every odd
+    /// row is shoved half a cell to the east; this is how a hex map
+    /// is stored in a `Grid`
+    pub fn to_offset(&self) -> Coord2D<T> {
+        let two = T::one() + T::one();
+        let col = self.q + (self.r - self.r.mod_floor(&two)) / two;
+        Coord2D::new(self.r, col)
+    }
+
+    /// The inverse of `Hex::to_offset`
+    pub fn from_offset(coord: &Coord2D<T>) -> Self {
+        let two = T::one() + T::one();
+        let q = coord.col - (coord.row - coord.row.mod_floor(&two)) / two;
+        Self::new(q, coord.row)
+    }
+
+    fn unit(direction: &HexDirection) -> Self {
+        let (q, r) = match direction {
+            HexDirection::East => (T::one(), T::zero()),
+            HexDirection::NorthEast => (T::one(), -T::one()),
+            HexDirection::NorthWest => (T::zero(), -T::one()),
+            HexDirection::West => (-T::one(), T::zero()),
+            HexDirection::SouthWest => (-T::one(), T::one()),
+            HexDirection::SouthEast => (T::zero(), T::one()),
+        };
+        Self::new(q, r)
+    }
+}
+
+impl<T: Integer + Signed + Eq + Hash + Copy> ops::Add for Hex<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl<T: Integer + Signed + Eq + Hash + Copy> ops::Sub for Hex<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl<T: Integer + Signed + Eq + Hash + Copy> ops::Neg for Hex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl<T: Integer + Signed + Eq + Hash + Copy> ops::Mul<T> for Hex<T> {
+    type Output = Self;
+
+    fn mul(self, v: T) -> Self {
+        Hex::new(self.q * v, self.r * v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_hex() {
+        let origin: Hex<i32> = Hex::new(0, 0);
+        assert_eq!(Hex::from_cube(1, 2, -3), Some(Hex::new(1, 2)));
+        assert_eq!(Hex::from_cube(1, 2, 3), None);
+
+        for (neighbor, direction) in origin.neighbors().iter().zip(HexDirection::ALL) {
+            assert_eq!(origin.distance(neighbor), 1);
+            assert_eq!(neighbor.adjacent(&direction.opposite()), origin);
+        }
+        assert_eq!(origin.distance(&Hex::new(3, -1)), 3);
+
+        assert_eq!(origin.ring(0), vec![origin]);
+        let ring = origin.ring(2);
+        assert_eq!(ring.len(), 12);
+        assert!(ring.iter().all(|x| origin.distance(x) == 2));
+        let spiral: HashSet<Hex<i32>> = origin.spiral(2).into_iter().collect();
+        assert_eq!(spiral.len(), 19);
+
+        let east = origin.adjacent(&HexDirection::East);
+        assert_eq!(
+            east.rotate_cw(&origin),
+            origin.adjacent(&HexDirection::SouthEast)
+        );
+        assert_eq!(
+            east.rotate_ccw(&origin),
+            origin.adjacent(&HexDirection::NorthEast)
+        );
+    }
+
+    #[test]
+    fn test_offset() {
+        assert_eq!(Hex::new(0, 1).to_offset(), Coord2D::new(1, 0));
+        assert_eq!(Hex::new(-1, 2).to_offset(), Coord2D::new(2, 0));
+        for hex in Hex::<i64>::new(2, -3).spiral(3) {
+            assert_eq!(Hex::from_offset(&hex.to_offset()), hex);
+        }
+    }
+}
@@ -1,15 +1,33 @@
-use std::cmp::{Eq, PartialOrd};
+use std::cmp::{Eq, Ordering, PartialOrd};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops;
-use std::str::FromStr;
 
-use num::traits::Unsigned;
+use num::iter::{range_inclusive, RangeInclusive};
+use num::traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedSub, SaturatingAdd, SaturatingSub, ToPrimitive,
+    Unsigned,
+};
 use num::{Integer, Signed};
+use thiserror::Error;
 
 use direction::CardinalDirection;
 
-#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+mod coord_3d;
+mod coord_n;
+mod hex;
+mod offsets;
+mod parse;
+
+pub use coord_3d::Coord3D;
+pub use coord_n::CoordN;
+pub use hex::Hex;
+pub use offsets::NotADirectionError;
+pub use parse::{AxisOrder, Coord2DParser, ParseCoord2DError};
+
+/// Coords are ordered row-major (by row, then by column); for
+/// column-major order, see `ColMajor`
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Copy)]
 pub struct Coord2D<T: Integer + PartialOrd + Eq + Hash + Copy> {
     pub row: T,
     pub col: T,
@@ -29,23 +47,95 @@ impl<T: Integer + PartialOrd + Eq + Copy + Hash> Coord2D<T> {
     }
 
     pub fn manhattan_distance(&self, other: &Self) -> T {
-        let n_streets = {
-            if self.row > other.row {
-                self.row - other.row
-            } else {
-                other.row - self.row
-            }
-        };
+        let n_streets = difference(self.row, other.row);
+        let n_avenues = difference(self.col, other.col);
+        n_streets + n_avenues
+    }
 
-        let n_avenues = {
-            if self.col > other.col {
-                self.col - other.col
-            } else {
-                other.col - self.col
-            }
-        };
+    /// The number of king's moves between two coords
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        std::cmp::max(
+            difference(self.row, other.row),
+            difference(self.col, other.col),
+        )
+    }
 
-        n_streets + n_avenues
+    pub fn squared_euclidean_distance(&self, other: &Self) -> T {
+        let d_row = difference(self.row, other.row);
+        let d_col = difference(self.col, other.col);
+        d_row * d_row + d_col * d_col
+    }
+}
+
+/// The (nonnegative) distance between a and b, without underflowing unsigned types
+fn difference<T: Integer + Copy>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Integer + Signed + PartialOrd + Eq + Copy + Hash> Coord2D<T> {
+    /// The smallest vector pointing the same way, so that stepping by it
+    /// visits every lattice point along the line; the zero vector is unchanged
+    pub fn reduced(&self) -> Self {
+        let divisor = self.row.gcd(&self.col);
+        if divisor.is_zero() {
+            *self
+        } else {
+            Self::new(self.row / divisor, self.col / divisor)
+        }
+    }
+
+    /// Positive if other is clockwise of self (with rows increasing
+    /// downward), negative if counterclockwise, zero if they're parallel
+    pub fn cross(&self, other: &Self) -> T {
+        self.col * other.row - self.row * other.col
+    }
+
+    /// Rotate a quarter turn clockwise about origin (so north becomes east)
+    pub fn rotate_cw(&self, origin: &Self) -> Self {
+        let offset = *self - *origin;
+        *origin + Self::new(offset.col, -offset.row)
+    }
+
+    /// Rotate a quarter turn counterclockwise about origin (so north becomes west)
+    pub fn rotate_ccw(&self, origin: &Self) -> Self {
+        let offset = *self - *origin;
+        *origin + Self::new(-offset.col, offset.row)
+    }
+}
+
+impl<
+        T: Integer + PartialOrd + Eq + Copy + Hash + Bounded + CheckedAdd + CheckedSub + ToPrimitive,
+    > Coord2D<T>
+{
+    /// The values within radius of x, clamped to T's range
+    fn span_around(x: T, radius: T) -> RangeInclusive<T> {
+        range_inclusive(
+            x.checked_sub(&radius).unwrap_or(T::min_value()),
+            x.checked_add(&radius).unwrap_or(T::max_value()),
+        )
+    }
+
+    /// Every coord (including this one) within a manhattan distance of radius,
+    /// in row-major order; coords that T can't represent are skipped
+    pub fn within_manhattan(&self, radius: T) -> impl Iterator<Item = Self> {
+        let center = *self;
+        Self::span_around(center.row, radius).flat_map(move |row| {
+            let remaining = radius - difference(row, center.row);
+            Self::span_around(center.col, remaining).map(move |col| Self::new(row, col))
+        })
+    }
+
+    /// Every coord (including this one) within a chebyshev distance of radius,
+    /// in row-major order; coords that T can't represent are skipped
+    pub fn within_chebyshev(&self, radius: T) -> impl Iterator<Item = Self> {
+        let center = *self;
+        Self::span_around(center.row, radius).flat_map(move |row| {
+            Self::span_around(center.col, radius).map(move |col| Self::new(row, col))
+        })
     }
 }
 
@@ -57,7 +147,26 @@ impl<T: Integer + PartialOrd + Eq + Copy + Hash> ops::Add<Coord2D<T>> for Coord2
     }
 }
 
-impl<T: Integer + Signed + PartialOrd + Eq + Copy + Hash> ops::Sub<Coord2D<T>> for Coord2D<T> {
+/// A coord ordered column-major (by column, then by row), e.g. as
+/// the key of a `BTreeSet` swept from west to east
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub struct ColMajor<T: Integer + PartialOrd + Eq + Hash + Copy>(pub Coord2D<T>);
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> Ord for ColMajor<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0.col, self.0.row).cmp(&(other.0.col, other.0.row))
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Hash + Copy> PartialOrd for ColMajor<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Panics on underflow (in debug builds) like the underlying integers,
+/// so subtracting a larger unsigned coord needs `checked_sub` instead
+impl<T: Integer + PartialOrd + Eq + Copy + Hash> ops::Sub<Coord2D<T>> for Coord2D<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -65,6 +174,152 @@ impl<T: Integer + Signed + PartialOrd + Eq + Copy + Hash> ops::Sub<Coord2D<T>> f
     }
 }
 
+impl<T: Integer + Signed + PartialOrd + Eq + Copy + Hash> ops::Neg for Coord2D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Coord2D::new(-self.row, -self.col)
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Copy + Hash> ops::Mul<T> for Coord2D<T> {
+    type Output = Self;
+
+    fn mul(self, v: T) -> Self {
+        Coord2D::new(self.row * v, self.col * v)
+    }
+}
+
+/// Integer division of each component, rounding toward zero
+impl<T: Integer + PartialOrd + Eq + Copy + Hash> ops::Div<T> for Coord2D<T> {
+    type Output = Self;
+
+    fn div(self, v: T) -> Self {
+        Coord2D::new(self.row / v, self.col / v)
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Copy + Hash> ops::AddAssign<Coord2D<T>> for Coord2D<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.row = self.row + other.row;
+        self.col = self.col + other.col;
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Copy + Hash> ops::SubAssign<Coord2D<T>> for Coord2D<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.row = self.row - other.row;
+        self.col = self.col - other.col;
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Copy + Hash> ops::MulAssign<T> for Coord2D<T> {
+    fn mul_assign(&mut self, v: T) {
+        self.row = self.row * v;
+        self.col = self.col * v;
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Copy + Hash + CheckedAdd + CheckedSub + CheckedMul> Coord2D<T> {
+    /// None if either component overflows
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add(&other.row)?,
+            self.col.checked_add(&other.col)?,
+        ))
+    }
+
+    /// None if either component overflows (or, for unsigned coords, goes below zero)
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_sub(&other.row)?,
+            self.col.checked_sub(&other.col)?,
+        ))
+    }
+
+    pub fn checked_mul_scalar(&self, v: T) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_mul(&v)?,
+            self.col.checked_mul(&v)?,
+        ))
+    }
+}
+
+impl<T: Integer + PartialOrd + Eq + Copy + Hash + SaturatingAdd + SaturatingSub> Coord2D<T> {
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        Self::new(
+            self.row.saturating_add(&other.row),
+            self.col.saturating_add(&other.col),
+        )
+    }
+
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        Self::new(
+            self.row.saturating_sub(&other.row),
+            self.col.saturating_sub(&other.col),
+        )
+    }
+}
+
+/// Offset an unsigned coord by a signed one of the same width
+macro_rules! impl_add_signed {
+    ($($unsigned:ty => $signed:ty),+) => {
+        $(
+            impl Coord2D<$unsigned> {
+                /// None if the result would be negative or overflow
+                pub fn checked_add_signed(&self, offset: &Coord2D<$signed>) -> Option<Self> {
+                    Some(Self::new(
+                        self.row.checked_add_signed(offset.row)?,
+                        self.col.checked_add_signed(offset.col)?,
+                    ))
+                }
+
+                pub fn saturating_add_signed(&self, offset: &Coord2D<$signed>) -> Self {
+                    Self::new(
+                        self.row.saturating_add_signed(offset.row),
+                        self.col.saturating_add_signed(offset.col),
+                    )
+                }
+            }
+        )+
+    };
+}
+
+impl_add_signed!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, usize => isize);
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("coordinate component out of range for the target type")]
+pub struct CoordOverflowError;
+
+/// Convert between integer widths; fails if either component doesn't fit
+macro_rules! impl_try_from {
+    ($from:ty => $($to:ty),+) => {
+        $(
+            impl TryFrom<Coord2D<$from>> for Coord2D<$to> {
+                type Error = CoordOverflowError;
+
+                fn try_from(coord: Coord2D<$from>) -> Result<Self, Self::Error> {
+                    Ok(Coord2D::new(
+                        <$to>::try_from(coord.row).map_err(|_| CoordOverflowError)?,
+                        <$to>::try_from(coord.col).map_err(|_| CoordOverflowError)?,
+                    ))
+                }
+            }
+        )+
+    };
+}
+
+impl_try_from!(u8 => u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_try_from!(u16 => u8, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_try_from!(u32 => u8, u16, u64, usize, i8, i16, i32, i64, isize);
+impl_try_from!(u64 => u8, u16, u32, usize, i8, i16, i32, i64, isize);
+impl_try_from!(usize => u8, u16, u32, u64, i8, i16, i32, i64, isize);
+impl_try_from!(i8 => u8, u16, u32, u64, usize, i16, i32, i64, isize);
+impl_try_from!(i16 => u8, u16, u32, u64, usize, i8, i32, i64, isize);
+impl_try_from!(i32 => u8, u16, u32, u64, usize, i8, i16, i64, isize);
+impl_try_from!(i64 => u8, u16, u32, u64, usize, i8, i16, i32, isize);
+impl_try_from!(isize => u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
 impl<T: Integer + Unsigned + PartialOrd + Eq + Copy + Hash> Coord2D<T> {
     pub fn neighbors(&self) -> Vec<Self> {
         let mut result: Vec<Self> = Vec::new();
@@ -176,24 +431,9 @@ impl<T: Integer + Unsigned + PartialOrd + Eq + Copy + Hash> Coord2D<T> {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct ParseCoord2DUsizeError;
-
-impl FromStr for Coord2D<usize> {
-    type Err = ParseCoord2DUsizeError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut iter = s.split(",");
-        Ok(Coord2D::new(
-            iter.next().unwrap().parse().unwrap(),
-            iter.next().unwrap().parse().unwrap(),
-        ))
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use std::collections::{BTreeSet, HashSet};
 
     use super::*;
 
@@ -211,4 +451,145 @@ mod tests {
         let neighbors: HashSet<Coord2D<usize>> = HashSet::from_iter(x.neighbors());
         assert_eq!(neighbors.len(), 5);
     }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut x: Coord2D<isize> = Coord2D::new(3, -4);
+        assert_eq!(x - Coord2D::new(1, 1), Coord2D::new(2, -5));
+        assert_eq!(-x, Coord2D::new(-3, 4));
+        assert_eq!(x * 2, Coord2D::new(6, -8));
+        assert_eq!(x / 2, Coord2D::new(1, -2));
+
+        x += Coord2D::new(1, 1);
+        x -= Coord2D::new(0, 2);
+        x *= -1;
+        assert_eq!(x, Coord2D::new(-4, 5));
+
+        let y: Coord2D<usize> = Coord2D::new(5, 2);
+        assert_eq!(y - Coord2D::new(1, 2), Coord2D::new(4, 0));
+        assert_eq!(y.checked_sub(&Coord2D::new(1, 3)), None);
+        assert_eq!(y.checked_add(&Coord2D::new(usize::MAX, 0)), None);
+        assert_eq!(y.checked_mul_scalar(3), Some(Coord2D::new(15, 6)));
+        assert_eq!(y.saturating_sub(&Coord2D::new(1, 3)), Coord2D::new(4, 0));
+        assert_eq!(
+            y.checked_add_signed(&Coord2D::new(-5, 1)),
+            Some(Coord2D::new(0, 3))
+        );
+        assert_eq!(y.checked_add_signed(&Coord2D::new(-6, 1)), None);
+        assert_eq!(
+            y.saturating_add_signed(&Coord2D::new(-6, 1)),
+            Coord2D::new(0, 3)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a: Coord2D<usize> = Coord2D::new(1, 5);
+        let b: Coord2D<usize> = Coord2D::new(4, 1);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.squared_euclidean_distance(&b), 25);
+    }
+
+    #[test]
+    fn test_geometry() {
+        let v: Coord2D<isize> = Coord2D::new(-4, 6);
+        assert_eq!(v.reduced(), Coord2D::new(-2, 3));
+        assert_eq!(Coord2D::new(0, -5).reduced(), Coord2D::new(0, -1));
+        assert_eq!(Coord2D::new(0, 0).reduced(), Coord2D::new(0, 0));
+
+        let north: Coord2D<isize> = Coord2D::new(-1, 0);
+        let east: Coord2D<isize> = Coord2D::new(0, 1);
+        assert!(north.cross(&east) > 0);
+        assert!(east.cross(&north) < 0);
+        assert_eq!(north.cross(&(north * 3)), 0);
+
+        let origin = Coord2D::new(0, 0);
+        assert_eq!(north.rotate_cw(&origin), east);
+        assert_eq!(east.rotate_ccw(&origin), north);
+        assert_eq!(
+            Coord2D::new(1, 3).rotate_cw(&Coord2D::new(1, 1)),
+            Coord2D::new(3, 1)
+        );
+    }
+
+    #[test]
+    fn test_within() {
+        let center: Coord2D<isize> = Coord2D::new(0, 0);
+        let diamond: Vec<_> = center.within_manhattan(2).collect();
+        assert_eq!(diamond.len(), 13);
+        assert!(diamond.iter().all(|x| x.manhattan_distance(&center) <= 2));
+        assert_eq!(center.within_chebyshev(2).count(), 25);
+
+        // clipped at zero for unsigned coords
+        let corner: Coord2D<usize> = Coord2D::new(0, 1);
+        assert_eq!(
+            corner.within_manhattan(1).collect::<Vec<_>>(),
+            vec![
+                Coord2D::new(0, 0),
+                Coord2D::new(0, 1),
+                Coord2D::new(0, 2),
+                Coord2D::new(1, 1)
+            ]
+        );
+        assert_eq!(corner.within_chebyshev(1).count(), 6);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let coords = [
+            Coord2D::new(1, 0),
+            Coord2D::new(0, 2),
+            Coord2D::new(0, 1),
+            Coord2D::new(2, 0),
+        ];
+
+        let row_major: Vec<Coord2D<usize>> = coords
+            .iter()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        assert_eq!(
+            row_major,
+            vec![
+                Coord2D::new(0, 1),
+                Coord2D::new(0, 2),
+                Coord2D::new(1, 0),
+                Coord2D::new(2, 0)
+            ]
+        );
+
+        let col_major: Vec<Coord2D<usize>> = coords
+            .iter()
+            .map(|x| ColMajor(*x))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|x| x.0)
+            .collect();
+        assert_eq!(
+            col_major,
+            vec![
+                Coord2D::new(1, 0),
+                Coord2D::new(2, 0),
+                Coord2D::new(0, 1),
+                Coord2D::new(0, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let x: Coord2D<usize> = Coord2D::new(3, 300);
+        assert_eq!(Coord2D::<isize>::try_from(x), Ok(Coord2D::new(3, 300)));
+        assert_eq!(Coord2D::<u8>::try_from(x), Err(CoordOverflowError));
+        assert_eq!(
+            Coord2D::<usize>::try_from(Coord2D::<i32>::new(-1, 0)),
+            Err(CoordOverflowError)
+        );
+        assert_eq!(
+            Coord2D::<i64>::try_from(Coord2D::<u8>::new(1, 2)),
+            Ok(Coord2D::new(1, 2))
+        );
+    }
 }
@@ -0,0 +1,104 @@
+use direction::{CardinalDirection, OrdinalDirection};
+use thiserror::Error;
+
+use crate::Coord2D;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("({row}, {col}) isn't the unit offset of a direction")]
+pub struct NotADirectionError {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// The unit (row, col) step in a direction, with rows increasing southward
+impl From<CardinalDirection> for Coord2D<isize> {
+    fn from(direction: CardinalDirection) -> Self {
+        Coord2D::from(OrdinalDirection::from(direction))
+    }
+}
+
+/// The unit (row, col) step in a direction, with rows increasing southward
+impl From<OrdinalDirection> for Coord2D<isize> {
+    fn from(direction: OrdinalDirection) -> Self {
+        match direction {
+            OrdinalDirection::North => Coord2D::new(-1, 0),
+            OrdinalDirection::NorthEast => Coord2D::new(-1, 1),
+            OrdinalDirection::East => Coord2D::new(0, 1),
+            OrdinalDirection::SouthEast => Coord2D::new(1, 1),
+            OrdinalDirection::South => Coord2D::new(1, 0),
+            OrdinalDirection::SouthWest => Coord2D::new(1, -1),
+            OrdinalDirection::West => Coord2D::new(0, -1),
+            OrdinalDirection::NorthWest => Coord2D::new(-1, -1),
+        }
+    }
+}
+
+impl TryFrom<Coord2D<isize>> for OrdinalDirection {
+    type Error = NotADirectionError;
+
+    fn try_from(offset: Coord2D<isize>) -> Result<Self, Self::Error> {
+        OrdinalDirection::ALL
+            .into_iter()
+            .find(|direction| Coord2D::from(*direction) == offset)
+            .ok_or(NotADirectionError {
+                row: offset.row,
+                col: offset.col,
+            })
+    }
+}
+
+impl TryFrom<Coord2D<isize>> for CardinalDirection {
+    type Error = NotADirectionError;
+
+    fn try_from(offset: Coord2D<isize>) -> Result<Self, Self::Error> {
+        OrdinalDirection::try_from(offset)
+            .ok()
+            .and_then(|direction| CardinalDirection::try_from(direction).ok())
+            .ok_or(NotADirectionError {
+                row: offset.row,
+                col: offset.col,
+            })
+    }
+}
+
+impl Coord2D<usize> {
+    /// The coord one step away in direction; None if that's off
+    /// the top or left edge
+    pub fn adjacent_ordinal(&self, direction: &OrdinalDirection) -> Option<Self> {
+        self.checked_add_signed(&Coord2D::from(*direction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offsets() {
+        for direction in OrdinalDirection::ALL {
+            let offset = Coord2D::from(direction);
+            assert_eq!(offset.chebyshev_distance(&Coord2D::new(0, 0)), 1);
+            assert_eq!(OrdinalDirection::try_from(offset), Ok(direction));
+            assert_eq!(-offset, Coord2D::from(direction.opposite()));
+        }
+        assert_eq!(
+            CardinalDirection::try_from(Coord2D::new(0, -1)),
+            Ok(CardinalDirection::West)
+        );
+        assert_eq!(
+            CardinalDirection::try_from(Coord2D::new(1, 1)),
+            Err(NotADirectionError { row: 1, col: 1 })
+        );
+        assert_eq!(
+            OrdinalDirection::try_from(Coord2D::new(0, 2)),
+            Err(NotADirectionError { row: 0, col: 2 })
+        );
+
+        let x: Coord2D<usize> = Coord2D::new(0, 3);
+        assert_eq!(
+            x.adjacent_ordinal(&OrdinalDirection::SouthWest),
+            x.south_west()
+        );
+        assert_eq!(x.adjacent_ordinal(&OrdinalDirection::NorthEast), None);
+    }
+}
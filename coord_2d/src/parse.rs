@@ -0,0 +1,192 @@
+use std::hash::Hash;
+use std::str::FromStr;
+
+use num::Integer;
+use thiserror::Error;
+
+use crate::Coord2D;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseCoord2DError {
+    #[error("expected 2 components but found {found}")]
+    ComponentCount { found: usize },
+    #[error("invalid component {component:?} at position {position}")]
+    InvalidComponent { component: String, position: usize },
+}
+
+/// Which component of "a,b" is the row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxisOrder {
+    /// "row,col"
+    RowCol,
+    /// "x,y", where x is the column and y is the row
+    XY,
+}
+
+/// Parses coords like "3,4" or "-1 2":
+///
+/// ```
+/// use coord_2d::{AxisOrder, Coord2D, Coord2DParser};
+///
+/// let parser = Coord2DParser::new().separator(' ').order(AxisOrder::XY);
+/// assert_eq!(parser.parse::<isize>("4  -3"), Ok(Coord2D::new(-3, 4)));
+/// ```
+///
+/// Whitespace around each component is ignored; a whitespace separator
+/// matches any run of whitespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coord2DParser {
+    separator: char,
+    order: AxisOrder,
+}
+
+impl Default for Coord2DParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Coord2DParser {
+    /// Parses "row,col"
+    pub fn new() -> Self {
+        Self {
+            separator: ',',
+            order: AxisOrder::RowCol,
+        }
+    }
+
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    pub fn order(mut self, order: AxisOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn parse<T>(&self, s: &str) -> Result<Coord2D<T>, ParseCoord2DError>
+    where
+        T: Integer + PartialOrd + Eq + Hash + Copy + FromStr,
+    {
+        let components = self.split(s);
+        if components.len() != 2 {
+            return Err(ParseCoord2DError::ComponentCount {
+                found: components.len(),
+            });
+        }
+
+        let parsed = components
+            .iter()
+            .map(|(position, component)| {
+                component
+                    .parse::<T>()
+                    .map_err(|_| ParseCoord2DError::InvalidComponent {
+                        component: component.to_string(),
+                        position: *position,
+                    })
+            })
+            .collect::<Result<Vec<T>, ParseCoord2DError>>()?;
+
+        Ok(match self.order {
+            AxisOrder::RowCol => Coord2D::new(parsed[0], parsed[1]),
+            AxisOrder::XY => Coord2D::new(parsed[1], parsed[0]),
+        })
+    }
+
+    /// The trimmed components of s, each with the byte offset where it starts
+    fn split<'a>(&self, s: &'a str) -> Vec<(usize, &'a str)> {
+        let mut result: Vec<(usize, &'a str)> = Vec::new();
+        let mut start: usize = 0;
+        let is_separator = |c: char| {
+            if self.separator.is_whitespace() {
+                c.is_whitespace()
+            } else {
+                c == self.separator
+            }
+        };
+        let boundaries = s
+            .char_indices()
+            .filter(|(_, c)| is_separator(*c))
+            .map(|(idx, c)| (idx, idx + c.len_utf8()))
+            .chain([(s.len(), s.len())]);
+        for (end, next_start) in boundaries {
+            let raw = &s[start..end];
+            let component = raw.trim();
+            let position = start + (raw.len() - raw.trim_start().len());
+            // runs of whitespace separate just like a single space does
+            if !(self.separator.is_whitespace() && component.is_empty()) {
+                result.push((position, component));
+            }
+            start = next_start;
+        }
+        result
+    }
+}
+
+/// Parses "row,col"; see `Coord2DParser` for other formats
+impl<T: Integer + PartialOrd + Eq + Hash + Copy + FromStr> FromStr for Coord2D<T> {
+    type Err = ParseCoord2DError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Coord2DParser::new().parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3,4".parse::<Coord2D<usize>>(), Ok(Coord2D::new(3, 4)));
+        assert_eq!(" -3 , 4".parse::<Coord2D<i32>>(), Ok(Coord2D::new(-3, 4)));
+        assert_eq!(
+            Coord2DParser::new()
+                .order(AxisOrder::XY)
+                .parse::<usize>("3,4"),
+            Ok(Coord2D::new(4, 3))
+        );
+        assert_eq!(
+            Coord2DParser::new().separator(';').parse::<u8>("1;2"),
+            Ok(Coord2D::new(1, 2))
+        );
+        assert_eq!(
+            Coord2DParser::new().separator(' ').parse::<i32>("1\t 2"),
+            Ok(Coord2D::new(1, 2))
+        );
+        assert_eq!(
+            Coord2DParser::new()
+                .separator('\t')
+                .parse::<i32>(" 1 \t2\t"),
+            Ok(Coord2D::new(1, 2))
+        );
+
+        assert_eq!(
+            "3,-4".parse::<Coord2D<usize>>(),
+            Err(ParseCoord2DError::InvalidComponent {
+                component: "-4".to_string(),
+                position: 2
+            })
+        );
+        assert_eq!(
+            "3, x".parse::<Coord2D<usize>>(),
+            Err(ParseCoord2DError::InvalidComponent {
+                component: "x".to_string(),
+                position: 3
+            })
+        );
+        assert_eq!(
+            "3,4,5".parse::<Coord2D<usize>>(),
+            Err(ParseCoord2DError::ComponentCount { found: 3 })
+        );
+        assert_eq!(
+            "".parse::<Coord2D<usize>>(),
+            Err(ParseCoord2DError::ComponentCount { found: 1 })
+        );
+        assert_eq!(
+            Coord2DParser::new().separator(' ').parse::<usize>(" "),
+            Err(ParseCoord2DError::ComponentCount { found: 0 })
+        );
+    }
+}
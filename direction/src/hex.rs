@@ -0,0 +1,66 @@
+/// The six neighbors of a pointy-topped hexagon, whose rows of
+/// cells run east to west
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDirection {
+    /// Every direction, counterclockwise from east
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::NorthEast,
+        HexDirection::NorthWest,
+        HexDirection::West,
+        HexDirection::SouthWest,
+        HexDirection::SouthEast,
+    ];
+
+    /// Turn 60 degrees clockwise
+    pub fn turn_right(&self) -> Self {
+        match self {
+            HexDirection::East => HexDirection::SouthEast,
+            HexDirection::SouthEast => HexDirection::SouthWest,
+            HexDirection::SouthWest => HexDirection::West,
+            HexDirection::West => HexDirection::NorthWest,
+            HexDirection::NorthWest => HexDirection::NorthEast,
+            HexDirection::NorthEast => HexDirection::East,
+        }
+    }
+
+    /// Turn 60 degrees counterclockwise
+    pub fn turn_left(&self) -> Self {
+        match self {
+            HexDirection::East => HexDirection::NorthEast,
+            HexDirection::NorthEast => HexDirection::NorthWest,
+            HexDirection::NorthWest => HexDirection::West,
+            HexDirection::West => HexDirection::SouthWest,
+            HexDirection::SouthWest => HexDirection::SouthEast,
+            HexDirection::SouthEast => HexDirection::East,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_right().turn_right().turn_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in HexDirection::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+        }
+        assert_eq!(HexDirection::NorthEast.opposite(), HexDirection::SouthWest);
+        assert_eq!(HexDirection::East.turn_left(), HexDirection::NorthEast);
+    }
+}
@@ -1,12 +1,79 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+mod hex;
+mod ordinal;
+mod parse;
+
+pub use hex::HexDirection;
+pub use ordinal::OrdinalDirection;
+pub use parse::{DirectionAlphabet, ParseDirectionError};
+
+/// The discriminants are usable as indices (`direction as usize`)
+/// into per-direction tables like `[T; 4]`, and are the order directions
+/// sort in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CardinalDirection {
-    North,
-    East,
-    South,
-    West,
+    North = 0,
+    East = 1,
+    South = 2,
+    West = 3,
+}
+
+/// A change of heading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Around,
 }
 
 impl CardinalDirection {
+    /// Every direction, clockwise from north (so `ALL[d.index()] == d`)
+    pub const ALL: [CardinalDirection; 4] = [
+        CardinalDirection::North,
+        CardinalDirection::East,
+        CardinalDirection::South,
+        CardinalDirection::West,
+    ];
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Rotate by a number of quarter turns, clockwise if positive
+    pub fn rotate(&self, quarter_turns: isize) -> Self {
+        Self::ALL[(self.index() as isize + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    pub fn turn(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+            Turn::Around => self.opposite(),
+        }
+    }
+
+    /// The turn that changes this heading into other; None if they're the same
+    pub fn turn_to(&self, other: &Self) -> Option<Turn> {
+        match (other.index() + 4 - self.index()) % 4 {
+            1 => Some(Turn::Right),
+            2 => Some(Turn::Around),
+            3 => Some(Turn::Left),
+            _ => None,
+        }
+    }
+
+    /// The fewest quarter turns (in either direction) between two headings
+    pub fn quarter_turns_to(&self, other: &Self) -> usize {
+        match self.turn_to(other) {
+            None => 0,
+            Some(Turn::Left | Turn::Right) => 1,
+            Some(Turn::Around) => 2,
+        }
+    }
+
     pub fn turn_right(&self) -> Self {
         match self {
             CardinalDirection::North => CardinalDirection::East,
@@ -25,13 +92,44 @@ impl CardinalDirection {
         }
     }
 
+    /// Panics on anything but an arrow (`^ > v <`); see `TryFrom<char>`
+    /// and `DirectionAlphabet` for fallible parsing
     pub fn from_char(c: char) -> Self {
-        match c {
-            '^' => Self::North,
-            '>' => Self::East,
-            'v' => Self::South,
-            '<' => Self::West,
-            _ => panic!("bad direction char {}", c),
+        DirectionAlphabet::Arrows
+            .parse_symbol(c.encode_utf8(&mut [0; 4]))
+            .unwrap_or_else(|| panic!("bad direction char {}", c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for (idx, direction) in CardinalDirection::ALL.iter().enumerate() {
+            assert_eq!(direction.index(), idx);
+            assert_eq!(direction.rotate(1), direction.turn_right());
+            assert_eq!(direction.rotate(-1), direction.turn_left());
+            assert_eq!(direction.rotate(6), direction.opposite());
+            for turn in [Turn::Left, Turn::Right, Turn::Around] {
+                assert_eq!(direction.turn_to(&direction.turn(turn)), Some(turn));
+            }
+            assert_eq!(direction.turn_to(direction), None);
         }
+
+        assert_eq!(
+            CardinalDirection::North.quarter_turns_to(&CardinalDirection::West),
+            1
+        );
+        assert_eq!(
+            CardinalDirection::East.quarter_turns_to(&CardinalDirection::West),
+            2
+        );
+
+        let mut visits = [0; 4];
+        visits[CardinalDirection::South.index()] += 1;
+        visits[CardinalDirection::West as usize] += 2;
+        assert_eq!(visits, [0, 0, 1, 2]);
     }
 }
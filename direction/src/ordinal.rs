@@ -0,0 +1,147 @@
+use crate::CardinalDirection;
+
+/// The four cardinal directions plus the four diagonals between them.
+/// The discriminants are usable as indices (`direction as usize`)
+/// into per-direction tables like `[T; 8]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrdinalDirection {
+    North = 0,
+    NorthEast = 1,
+    East = 2,
+    SouthEast = 3,
+    South = 4,
+    SouthWest = 5,
+    West = 6,
+    NorthWest = 7,
+}
+
+impl OrdinalDirection {
+    /// Every direction, clockwise from north
+    pub const ALL: [OrdinalDirection; 8] = [
+        OrdinalDirection::North,
+        OrdinalDirection::NorthEast,
+        OrdinalDirection::East,
+        OrdinalDirection::SouthEast,
+        OrdinalDirection::South,
+        OrdinalDirection::SouthWest,
+        OrdinalDirection::West,
+        OrdinalDirection::NorthWest,
+    ];
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Rotate by a number of 45 degree steps, clockwise if positive
+    pub fn rotate(&self, n_steps: isize) -> Self {
+        Self::ALL[(self.index() as isize + n_steps).rem_euclid(8) as usize]
+    }
+
+    /// Turn 45 degrees clockwise
+    pub fn turn_right_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Turn 45 degrees counterclockwise
+    pub fn turn_left_45(&self) -> Self {
+        self.rotate(-1)
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<CardinalDirection> for OrdinalDirection {
+    fn from(direction: CardinalDirection) -> Self {
+        match direction {
+            CardinalDirection::North => OrdinalDirection::North,
+            CardinalDirection::East => OrdinalDirection::East,
+            CardinalDirection::South => OrdinalDirection::South,
+            CardinalDirection::West => OrdinalDirection::West,
+        }
+    }
+}
+
+/// Fails for the diagonals
+impl TryFrom<OrdinalDirection> for CardinalDirection {
+    type Error = OrdinalDirection;
+
+    fn try_from(direction: OrdinalDirection) -> Result<Self, Self::Error> {
+        match direction {
+            OrdinalDirection::North => Ok(CardinalDirection::North),
+            OrdinalDirection::East => Ok(CardinalDirection::East),
+            OrdinalDirection::South => Ok(CardinalDirection::South),
+            OrdinalDirection::West => Ok(CardinalDirection::West),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(
+            OrdinalDirection::North.turn_right_45(),
+            OrdinalDirection::NorthEast
+        );
+        assert_eq!(
+            OrdinalDirection::North.turn_left_45(),
+            OrdinalDirection::NorthWest
+        );
+        assert_eq!(
+            OrdinalDirection::SouthWest.turn_right(),
+            OrdinalDirection::NorthWest
+        );
+        assert_eq!(
+            OrdinalDirection::NorthEast.opposite(),
+            OrdinalDirection::SouthWest
+        );
+        assert_eq!(
+            OrdinalDirection::ALL
+                .iter()
+                .filter(|x| x.is_diagonal())
+                .count(),
+            4
+        );
+        for (idx, direction) in OrdinalDirection::ALL.iter().enumerate() {
+            assert_eq!(direction.index(), idx);
+        }
+        assert_eq!(
+            OrdinalDirection::North.rotate(-3),
+            OrdinalDirection::SouthWest
+        );
+        assert_eq!(
+            OrdinalDirection::West.rotate(11),
+            OrdinalDirection::NorthEast
+        );
+
+        for direction in [CardinalDirection::North, CardinalDirection::West] {
+            let ordinal = OrdinalDirection::from(direction);
+            assert_eq!(CardinalDirection::try_from(ordinal), Ok(direction));
+            assert_eq!(
+                OrdinalDirection::from(direction.turn_right()),
+                ordinal.turn_right()
+            );
+        }
+        assert_eq!(
+            CardinalDirection::try_from(OrdinalDirection::SouthEast),
+            Err(OrdinalDirection::SouthEast)
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::{CardinalDirection, OrdinalDirection};
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("unknown direction {found:?} at position {position}")]
+pub struct ParseDirectionError {
+    pub found: String,
+    /// The byte offset of `found` within the parsed string
+    pub position: usize,
+}
+
+/// A way of writing the four cardinal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DirectionAlphabet {
+    /// `^ > v <`
+    Arrows,
+    /// `N E S W`
+    Compass,
+    /// `U R D L`
+    UpDownLeftRight,
+    /// `north east south west`, in any case
+    Words,
+}
+
+impl DirectionAlphabet {
+    /// Every alphabet; no two of them share a symbol
+    pub const ALL: [DirectionAlphabet; 4] = [
+        DirectionAlphabet::Arrows,
+        DirectionAlphabet::Compass,
+        DirectionAlphabet::UpDownLeftRight,
+        DirectionAlphabet::Words,
+    ];
+
+    /// How a direction is written in this alphabet
+    pub fn symbol(&self, direction: &CardinalDirection) -> &'static str {
+        let symbols = match self {
+            DirectionAlphabet::Arrows => ["^", ">", "v", "<"],
+            DirectionAlphabet::Compass => ["N", "E", "S", "W"],
+            DirectionAlphabet::UpDownLeftRight => ["U", "R", "D", "L"],
+            DirectionAlphabet::Words => ["north", "east", "south", "west"],
+        };
+        match direction {
+            CardinalDirection::North => symbols[0],
+            CardinalDirection::East => symbols[1],
+            CardinalDirection::South => symbols[2],
+            CardinalDirection::West => symbols[3],
+        }
+    }
+
+    /// None if symbol isn't part of this alphabet
+    pub fn parse_symbol(&self, symbol: &str) -> Option<CardinalDirection> {
+        CardinalDirection::ALL
+            .into_iter()
+            .find(|direction| match self {
+                DirectionAlphabet::Words => self.symbol(direction).eq_ignore_ascii_case(symbol),
+                _ => self.symbol(direction) == symbol,
+            })
+    }
+
+    /// Parse a list of moves like "<^^>v", ignoring whitespace (including
+    /// line breaks). Words must be separated by whitespace or commas.
+    pub fn parse_instructions(
+        &self,
+        s: &str,
+    ) -> Result<Vec<CardinalDirection>, ParseDirectionError> {
+        let symbols: Vec<(usize, &str)> = match self {
+            DirectionAlphabet::Words => words(s),
+            _ => s
+                .char_indices()
+                .filter(|(_, c)| !c.is_whitespace())
+                .map(|(position, c)| (position, &s[position..position + c.len_utf8()]))
+                .collect(),
+        };
+
+        symbols
+            .into_iter()
+            .map(|(position, symbol)| {
+                self.parse_symbol(symbol).ok_or(ParseDirectionError {
+                    found: symbol.to_string(),
+                    position,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The words of s separated by whitespace or commas, each with the byte
+/// offset where it starts
+fn words(s: &str) -> Vec<(usize, &str)> {
+    let mut result: Vec<(usize, &str)> = Vec::new();
+    let mut word_start: Option<usize> = None;
+    // a trailing separator closes the last word
+    for (idx, c) in s.char_indices().chain([(s.len(), ' ')]) {
+        let is_separator = c.is_whitespace() || c == ',';
+        match (word_start, is_separator) {
+            (Some(start), true) => {
+                result.push((start, &s[start..idx]));
+                word_start = None;
+            }
+            (None, false) => word_start = Some(idx),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Accepts a symbol from any single-character alphabet
+impl TryFrom<char> for CardinalDirection {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let mut buf = [0; 4];
+        let symbol: &str = c.encode_utf8(&mut buf);
+        DirectionAlphabet::ALL
+            .iter()
+            .find_map(|alphabet| alphabet.parse_symbol(symbol))
+            .ok_or(ParseDirectionError {
+                found: c.to_string(),
+                position: 0,
+            })
+    }
+}
+
+/// Accepts a symbol from any alphabet, ignoring surrounding whitespace
+impl FromStr for CardinalDirection {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let symbol = s.trim();
+        DirectionAlphabet::ALL
+            .iter()
+            .find_map(|alphabet| alphabet.parse_symbol(symbol))
+            .ok_or(ParseDirectionError {
+                found: symbol.to_string(),
+                position: s.len() - s.trim_start().len(),
+            })
+    }
+}
+
+/// Written as an arrow (`^ > v <`); see `DirectionAlphabet::symbol` for others
+impl fmt::Display for CardinalDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", DirectionAlphabet::Arrows.symbol(self))
+    }
+}
+
+impl OrdinalDirection {
+    /// The compass abbreviation, e.g. "NE"
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            OrdinalDirection::North => "N",
+            OrdinalDirection::NorthEast => "NE",
+            OrdinalDirection::East => "E",
+            OrdinalDirection::SouthEast => "SE",
+            OrdinalDirection::South => "S",
+            OrdinalDirection::SouthWest => "SW",
+            OrdinalDirection::West => "W",
+            OrdinalDirection::NorthWest => "NW",
+        }
+    }
+}
+
+/// Accepts compass abbreviations like "NE", in any case
+impl FromStr for OrdinalDirection {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let symbol = s.trim();
+        OrdinalDirection::ALL
+            .into_iter()
+            .find(|direction| direction.abbreviation().eq_ignore_ascii_case(symbol))
+            .ok_or(ParseDirectionError {
+                found: symbol.to_string(),
+                position: s.len() - s.trim_start().len(),
+            })
+    }
+}
+
+/// Written as its compass abbreviation, e.g. "NE"
+impl fmt::Display for OrdinalDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!(
+            CardinalDirection::try_from('^'),
+            Ok(CardinalDirection::North)
+        );
+        assert_eq!(
+            CardinalDirection::try_from('L'),
+            Ok(CardinalDirection::West)
+        );
+        assert_eq!(
+            CardinalDirection::try_from('x'),
+            Err(ParseDirectionError {
+                found: "x".to_string(),
+                position: 0
+            })
+        );
+        assert_eq!(" South ".parse(), Ok(CardinalDirection::South));
+        assert_eq!("E".parse(), Ok(CardinalDirection::East));
+
+        for direction in [CardinalDirection::North, CardinalDirection::West] {
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+        }
+        assert_eq!("nw".parse(), Ok(OrdinalDirection::NorthWest));
+        assert_eq!(OrdinalDirection::SouthEast.to_string(), "SE");
+    }
+
+    #[test]
+    fn test_parse_instructions() {
+        assert_eq!(
+            DirectionAlphabet::Arrows.parse_instructions("<^\nv>"),
+            Ok(vec![
+                CardinalDirection::West,
+                CardinalDirection::North,
+                CardinalDirection::South,
+                CardinalDirection::East
+            ])
+        );
+        assert_eq!(
+            DirectionAlphabet::UpDownLeftRight.parse_instructions("UUDx"),
+            Err(ParseDirectionError {
+                found: "x".to_string(),
+                position: 3
+            })
+        );
+        assert_eq!(
+            DirectionAlphabet::Words.parse_instructions("north, EAST west"),
+            Ok(vec![
+                CardinalDirection::North,
+                CardinalDirection::East,
+                CardinalDirection::West
+            ])
+        );
+        assert_eq!(
+            DirectionAlphabet::Words.parse_instructions("north up"),
+            Err(ParseDirectionError {
+                found: "up".to_string(),
+                position: 6
+            })
+        );
+        // the no-break space is two bytes long
+        assert_eq!(
+            DirectionAlphabet::Words.parse_instructions("south\u{a0}up"),
+            Err(ParseDirectionError {
+                found: "up".to_string(),
+                position: 7
+            })
+        );
+    }
+}
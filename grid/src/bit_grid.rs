@@ -0,0 +1,237 @@
+use std::fmt::Display;
+use std::ops;
+
+use coord_2d::Coord2D;
+
+use crate::Grid;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A dense grid of booleans packed into 64 bit words, one run of words per row.
+/// Useful for wall maps and visited sets; cloning copies n_rows * n_cols / 8 bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    words_per_row: usize,
+    pub n_rows: usize,
+    pub n_cols: usize,
+}
+
+impl BitGrid {
+    /// An n_rows x n_cols grid with every bit unset
+    pub fn new(n_rows: usize, n_cols: usize) -> Self {
+        let words_per_row = n_cols.div_ceil(WORD_BITS);
+        Self {
+            words: vec![0; n_rows * words_per_row],
+            words_per_row,
+            n_rows,
+            n_cols,
+        }
+    }
+
+    /// Set the bit for every cell of `grid` whose value satisfies `predicate`
+    pub fn from_grid<T, F>(grid: &Grid<T>, predicate: F) -> Self
+    where
+        T: Copy + Display + PartialEq,
+        F: Fn(&T) -> bool,
+    {
+        let mut result = Self::new(grid.n_rows, grid.n_cols);
+        for (coord, val) in grid.coords_and_vals::<usize>() {
+            if predicate(&val) {
+                result.insert(&coord);
+            }
+        }
+        result
+    }
+
+    /// The index of the word holding (row, col) and the mask for its bit
+    fn locate(&self, coord: &Coord2D<usize>) -> (usize, u64) {
+        (
+            coord.row * self.words_per_row + coord.col / WORD_BITS,
+            1 << (coord.col % WORD_BITS),
+        )
+    }
+
+    fn in_bounds(&self, coord: &Coord2D<usize>) -> bool {
+        coord.row < self.n_rows && coord.col < self.n_cols
+    }
+
+    /// None if the coord is off the grid
+    pub fn get(&self, coord: &Coord2D<usize>) -> Option<bool> {
+        if !self.in_bounds(coord) {
+            return None;
+        }
+
+        let (word_idx, mask) = self.locate(coord);
+        Some(self.words[word_idx] & mask != 0)
+    }
+
+    /// False if the coord is off the grid
+    pub fn contains(&self, coord: &Coord2D<usize>) -> bool {
+        self.get(coord).unwrap_or(false)
+    }
+
+    /// Set the bit at coord; returns true if it was previously unset
+    pub fn insert(&mut self, coord: &Coord2D<usize>) -> bool {
+        if !self.in_bounds(coord) {
+            panic!(
+                "Invalid set coord ({}, {}); n_rows: {}, n_cols: {}",
+                coord.row, coord.col, self.n_rows, self.n_cols
+            )
+        }
+
+        let (word_idx, mask) = self.locate(coord);
+        let was_unset = self.words[word_idx] & mask == 0;
+        self.words[word_idx] |= mask;
+        was_unset
+    }
+
+    /// Unset the bit at coord; returns true if it was previously set
+    pub fn remove(&mut self, coord: &Coord2D<usize>) -> bool {
+        if !self.in_bounds(coord) {
+            return false;
+        }
+
+        let (word_idx, mask) = self.locate(coord);
+        let was_set = self.words[word_idx] & mask != 0;
+        self.words[word_idx] &= !mask;
+        was_set
+    }
+
+    /// Unset every bit
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// The number of set bits
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    /// Every set coord, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = Coord2D<usize>> + use<'_> {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(word_idx, word)| {
+                let row = word_idx / self.words_per_row;
+                let col_offset = (word_idx % self.words_per_row) * WORD_BITS;
+                let mut remaining = *word;
+                std::iter::from_fn(move || {
+                    if remaining == 0 {
+                        return None;
+                    }
+                    let bit = remaining.trailing_zeros() as usize;
+                    remaining &= remaining - 1;
+                    Some(Coord2D::new(row, col_offset + bit))
+                })
+            })
+    }
+
+    fn zip_words_with(&mut self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) {
+        if self.n_rows != other.n_rows || self.n_cols != other.n_cols {
+            panic!(
+                "Mismatched grid sizes ({}, {}) and ({}, {})",
+                self.n_rows, self.n_cols, other.n_rows, other.n_cols
+            );
+        }
+
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a = f(*a, *b);
+        }
+    }
+}
+
+impl ops::BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, rhs: &BitGrid) {
+        self.zip_words_with(rhs, |a, b| a & b);
+    }
+}
+
+impl ops::BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, rhs: &BitGrid) {
+        self.zip_words_with(rhs, |a, b| a | b);
+    }
+}
+
+impl ops::BitXorAssign<&BitGrid> for BitGrid {
+    fn bitxor_assign(&mut self, rhs: &BitGrid) {
+        self.zip_words_with(rhs, |a, b| a ^ b);
+    }
+}
+
+impl ops::BitAnd<&BitGrid> for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result &= rhs;
+        result
+    }
+}
+
+impl ops::BitOr<&BitGrid> for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result |= rhs;
+        result
+    }
+}
+
+impl ops::BitXor<&BitGrid> for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: &BitGrid) -> BitGrid {
+        let mut result = self.clone();
+        result ^= rhs;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_grid() {
+        let mut grid = BitGrid::new(3, 70);
+        assert!(grid.insert(&Coord2D::new(0, 0)));
+        assert!(grid.insert(&Coord2D::new(1, 65)));
+        assert!(grid.insert(&Coord2D::new(2, 3)));
+        assert!(!grid.insert(&Coord2D::new(2, 3)));
+
+        assert_eq!(grid.get(&Coord2D::new(1, 65)), Some(true));
+        assert_eq!(grid.get(&Coord2D::new(1, 64)), Some(false));
+        assert_eq!(grid.get(&Coord2D::new(1, 70)), None);
+        assert_eq!(grid.count_ones(), 3);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            vec![Coord2D::new(0, 0), Coord2D::new(1, 65), Coord2D::new(2, 3)]
+        );
+
+        assert!(grid.remove(&Coord2D::new(0, 0)));
+        assert!(!grid.remove(&Coord2D::new(0, 0)));
+        assert_eq!(grid.count_ones(), 2);
+    }
+
+    #[test]
+    fn test_bit_ops() {
+        let walls = BitGrid::from_grid(
+            &Grid::from_line_iter(["#..", ".#.", "..#"].into_iter().map(|x| x.to_string())),
+            |c| *c == '#',
+        );
+        let mut visited = BitGrid::new(3, 3);
+        visited.insert(&Coord2D::new(0, 0));
+        visited.insert(&Coord2D::new(0, 1));
+
+        assert_eq!((&walls & &visited).count_ones(), 1);
+        assert_eq!((&walls | &visited).count_ones(), 4);
+        assert_eq!((&walls ^ &visited).count_ones(), 3);
+    }
+}
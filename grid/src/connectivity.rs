@@ -0,0 +1,41 @@
+/// Which cells count as touching one another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// North, east, south, and west
+    Four,
+    /// The cardinal neighbors plus the four diagonals
+    Eight,
+    /// Any other set of (row, col) offsets, e.g. a knight's moves
+    Custom(&'static [(isize, isize)]),
+}
+
+impl Connectivity {
+    /// The (row, col) offsets of every neighbor
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(-1, 0), (0, 1), (1, 0), (0, -1)],
+            Connectivity::Eight => &[
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+            ],
+            Connectivity::Custom(offsets) => offsets,
+        }
+    }
+
+    /// The offsets of the neighbors that come after a cell in row-major order;
+    /// visiting only these is enough to see every adjacent pair. Custom offsets
+    /// needn't be symmetric, so all of them are visited.
+    pub(crate) fn forward_offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(0, 1), (1, 0)],
+            Connectivity::Eight => &[(0, 1), (1, -1), (1, 0), (1, 1)],
+            Connectivity::Custom(offsets) => offsets,
+        }
+    }
+}
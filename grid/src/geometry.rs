@@ -0,0 +1,216 @@
+use std::collections::{BTreeSet, HashSet};
+
+use num::Integer;
+
+use coord_2d::Coord2D;
+use direction::CardinalDirection;
+
+/// A closed polygon whose vertices lie on the lattice of cell corners;
+/// the corner (row, col) is the top left corner of the cell (row, col).
+///
+/// Polygons traced from a set of cells keep the cells on their right-hand
+/// side, so outer boundaries run clockwise (as drawn, with rows increasing
+/// downwards) and the boundaries of holes run counter-clockwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    pub vertices: Vec<Coord2D<isize>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Coord2D<isize>>) -> Self {
+        Self { vertices }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (&Coord2D<isize>, &Coord2D<isize>)> {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+    }
+
+    /// The number of straight sides (vertices where the boundary changes direction)
+    pub fn n_sides(&self) -> usize {
+        let n = self.vertices.len();
+        (0..n)
+            .filter(|idx| {
+                let previous = &self.vertices[(idx + n - 1) % n];
+                let current = &self.vertices[*idx];
+                let next = &self.vertices[(idx + 1) % n];
+
+                let incoming = *current - *previous;
+                let outgoing = *next - *current;
+                incoming.row * outgoing.col - incoming.col * outgoing.row != 0
+            })
+            .count()
+    }
+
+    /// The total length of the boundary, assuming every edge is horizontal or vertical
+    pub fn perimeter(&self) -> usize {
+        self.edges()
+            .map(|(a, b)| a.manhattan_distance(b) as usize)
+            .sum()
+    }
+
+    /// Twice the area enclosed by the polygon (the shoelace formula); positive
+    /// for clockwise polygons (outer boundaries) and negative for holes
+    pub fn signed_double_area(&self) -> isize {
+        self.edges()
+            .map(|(a, b)| a.col * b.row - b.col * a.row)
+            .sum()
+    }
+
+    /// The area enclosed by the polygon
+    pub fn area(&self) -> usize {
+        self.signed_double_area().unsigned_abs() / 2
+    }
+
+    pub fn is_hole(&self) -> bool {
+        self.signed_double_area() < 0
+    }
+
+    /// The number of lattice points that lie on the boundary
+    pub fn n_boundary_points(&self) -> usize {
+        self.edges()
+            .map(|(a, b)| {
+                let delta = *b - *a;
+                delta.row.gcd(&delta.col) as usize
+            })
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside the polygon (Pick's theorem:
+    /// area = interior + boundary / 2 - 1)
+    pub fn n_interior_points(&self) -> usize {
+        self.area() + 1 - self.n_boundary_points() / 2
+    }
+}
+
+/// Trace the boundaries of a set of cells. Every 4-connected group of cells
+/// has one outer boundary, plus one boundary for each hole inside of it.
+pub fn boundary_polygons(cells: &HashSet<Coord2D<usize>>) -> Vec<Polygon> {
+    // every cell side that faces a non-member, stored as (start corner, heading)
+    // so that walking the edge keeps the cell on the right
+    let mut edges: HashSet<(Coord2D<isize>, CardinalDirection)> = HashSet::new();
+    for cell in cells.iter() {
+        let row = cell.row as isize;
+        let col = cell.col as isize;
+        let is_member = |d_row: isize, d_col: isize| match (
+            cell.row.checked_add_signed(d_row),
+            cell.col.checked_add_signed(d_col),
+        ) {
+            (Some(r), Some(c)) => cells.contains(&Coord2D::new(r, c)),
+            _ => false,
+        };
+
+        if !is_member(-1, 0) {
+            edges.insert((Coord2D::new(row, col), CardinalDirection::East));
+        }
+        if !is_member(0, 1) {
+            edges.insert((Coord2D::new(row, col + 1), CardinalDirection::South));
+        }
+        if !is_member(1, 0) {
+            edges.insert((Coord2D::new(row + 1, col + 1), CardinalDirection::West));
+        }
+        if !is_member(0, -1) {
+            edges.insert((Coord2D::new(row + 1, col), CardinalDirection::North));
+        }
+    }
+
+    let mut result: Vec<Polygon> = Vec::new();
+    // always start from the first unvisited edge in (corner, heading) order,
+    // so the output doesn't depend on hashing
+    let mut unvisited: BTreeSet<(Coord2D<isize>, CardinalDirection)> =
+        edges.iter().copied().collect();
+    while let Some(first) = unvisited.first().copied() {
+        let mut vertices: Vec<Coord2D<isize>> = Vec::new();
+        let mut current = first;
+        loop {
+            unvisited.remove(&current);
+            let (start, heading) = &current;
+            let end = *start + Coord2D::from(*heading);
+
+            // prefer turning right so that cells touching only at a corner
+            // end up with separate boundaries
+            let next_heading = [heading.turn_right(), *heading, heading.turn_left()]
+                .into_iter()
+                .find(|h| edges.contains(&(end, *h)))
+                .expect("cell boundaries are always closed");
+            if next_heading != *heading {
+                vertices.push(end);
+            }
+
+            current = (end, next_heading);
+            if current == first {
+                break;
+            }
+        }
+
+        result.push(Polygon::new(vertices));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boundary_polygons() {
+        // a 3x3 ring (with a hole in the middle) and a separate L-shape
+        let mut cells: HashSet<Coord2D<usize>> = [(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+            .into_iter()
+            .chain([(2, 0), (2, 1), (2, 2)])
+            .chain([(4, 0), (5, 0), (5, 1)])
+            .map(|(row, col)| Coord2D::new(row, col))
+            .collect();
+
+        let polygons = boundary_polygons(&cells);
+        let outer: Vec<&Polygon> = polygons.iter().filter(|p| !p.is_hole()).collect();
+        let holes: Vec<&Polygon> = polygons.iter().filter(|p| p.is_hole()).collect();
+        assert_eq!(outer.len(), 2);
+        assert_eq!(holes.len(), 1);
+
+        assert_eq!(outer[0].vertices[0], Coord2D::new(0, 3));
+        assert_eq!(outer[0].n_sides(), 4);
+        assert_eq!(outer[0].perimeter(), 12);
+        assert_eq!(outer[0].area(), 9);
+        assert_eq!(outer[0].n_interior_points(), 4);
+
+        assert_eq!(holes[0].n_sides(), 4);
+        assert_eq!(holes[0].area(), 1);
+
+        assert_eq!(outer[1].n_sides(), 6);
+        assert_eq!(outer[1].perimeter(), 8);
+        assert_eq!(outer[1].area(), 3);
+
+        // the enclosed area of all boundaries together is the number of cells
+        let total: isize = polygons.iter().map(|p| p.signed_double_area()).sum();
+        assert_eq!(total, 2 * cells.len() as isize);
+
+        // cells that only touch at a corner get separate boundaries
+        // and start from the first edge in (corner, heading) order, even at
+        // the pinch corner (1, 1) where two edges start
+        cells = HashSet::from([Coord2D::new(0, 0), Coord2D::new(1, 1)]);
+        let vertices: Vec<Vec<Coord2D<isize>>> = boundary_polygons(&cells)
+            .into_iter()
+            .map(|p| p.vertices)
+            .collect();
+        assert_eq!(
+            vertices,
+            vec![
+                vec![
+                    Coord2D::new(0, 1),
+                    Coord2D::new(1, 1),
+                    Coord2D::new(1, 0),
+                    Coord2D::new(0, 0)
+                ],
+                vec![
+                    Coord2D::new(1, 2),
+                    Coord2D::new(2, 2),
+                    Coord2D::new(2, 1),
+                    Coord2D::new(1, 1)
+                ],
+            ]
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt::Display;
+
+use coord_2d::{Coord2D, Hex};
+use direction::CardinalDirection;
+use utils::DijkstraSearchable;
+
+use crate::connectivity::Connectivity;
+use crate::Grid;
+
+type PassableFn<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+type CostFn<'a, T> = Box<dyn Fn(&T) -> usize + 'a>;
+
+/// A grid searchable with `utils::shortest_path_length` and friends: the nodes
+/// are the passable cells, and moving into a cell costs `cost` of its value
+/// (1 by default)
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use coord_2d::Coord2D;
+/// use grid::{Grid, GridGraph};
+/// use utils::shortest_path_length;
+///
+/// let grid = Grid::from_line_iter(["..#", "#..", "..."].into_iter().map(|x| x.to_string()));
+/// let graph = GridGraph::new(&grid, |c| *c != '#');
+/// let end = HashSet::from([Coord2D::new(2, 0)]);
+/// assert_eq!(shortest_path_length(graph, Coord2D::new(0, 0), end), Some(4));
+/// ```
+pub struct GridGraph<'a, T: Copy + Display + PartialEq> {
+    grid: &'a Grid<T>,
+    passable: PassableFn<'a, T>,
+    cost: CostFn<'a, T>,
+    connectivity: Connectivity,
+}
+
+impl<'a, T: Copy + Display + PartialEq> GridGraph<'a, T> {
+    pub fn new(grid: &'a Grid<T>, passable: impl Fn(&T) -> bool + 'a) -> Self {
+        Self {
+            grid,
+            passable: Box::new(passable),
+            cost: Box::new(|_| 1),
+            connectivity: Connectivity::Four,
+        }
+    }
+
+    /// The cost of moving into a cell with the given value
+    pub fn cost(mut self, cost: impl Fn(&T) -> usize + 'a) -> Self {
+        self.cost = Box::new(cost);
+        self
+    }
+
+    pub fn connectivity(mut self, connectivity: Connectivity) -> Self {
+        self.connectivity = connectivity;
+        self
+    }
+
+    /// Search over (coord, heading) instead, where each step moves forward
+    /// and turning in place costs `turn_cost` per quarter turn. Headings are
+    /// always cardinal, so only `Connectivity::Four` is supported.
+    pub fn headings(self, turn_cost: usize) -> HeadingGridGraph<'a, T> {
+        debug_assert_eq!(
+            self.connectivity,
+            Connectivity::Four,
+            "heading graphs only step in the four cardinal directions"
+        );
+        HeadingGridGraph {
+            grid: self.grid,
+            passable: self.passable,
+            cost: self.cost,
+            turn_cost,
+        }
+    }
+}
+
+impl<T: Copy + Display + PartialEq> DijkstraSearchable for GridGraph<'_, T> {
+    type Node = Coord2D<usize>;
+    type Cost = usize;
+
+    fn neighbors(
+        &self,
+        previous: &Coord2D<usize>,
+        previous_cost: usize,
+    ) -> Vec<(Coord2D<usize>, usize)> {
+        self.grid
+            .neighbors(previous, self.connectivity)
+            .filter(|(_, val)| (self.passable)(val))
+            .map(|(coord, val)| (coord, previous_cost + (self.cost)(&val)))
+            .collect()
+    }
+}
+
+/// A grid whose nodes are a passable cell plus the direction you're facing
+/// in it; created by `GridGraph::headings`
+pub struct HeadingGridGraph<'a, T: Copy + Display + PartialEq> {
+    grid: &'a Grid<T>,
+    passable: PassableFn<'a, T>,
+    cost: CostFn<'a, T>,
+    turn_cost: usize,
+}
+
+impl<T: Copy + Display + PartialEq> DijkstraSearchable for HeadingGridGraph<'_, T> {
+    type Node = (Coord2D<usize>, CardinalDirection);
+    type Cost = usize;
+
+    fn neighbors(
+        &self,
+        previous: &(Coord2D<usize>, CardinalDirection),
+        previous_cost: usize,
+    ) -> Vec<((Coord2D<usize>, CardinalDirection), usize)> {
+        let (coord, heading) = previous;
+        let mut result: Vec<((Coord2D<usize>, CardinalDirection), usize)> = CardinalDirection::ALL
+            .into_iter()
+            .filter(|other| other != heading)
+            .map(|other| {
+                let turn_cost = self.turn_cost * heading.quarter_turns_to(&other);
+                ((*coord, other), previous_cost + turn_cost)
+            })
+            .collect();
+
+        if let Some((next, val)) = self.grid.ray_towards(coord, heading).next() {
+            if (self.passable)(&val) {
+                result.push(((next, *heading), previous_cost + (self.cost)(&val)));
+            }
+        }
+        result
+    }
+}
+
+/// A hex map stored in a grid (see `Hex::to_offset` for the layout),
+/// searchable with `utils::shortest_path_length` and friends
+pub struct HexGridGraph<'a, T: Copy + Display + PartialEq> {
+    grid: &'a Grid<T>,
+    passable: PassableFn<'a, T>,
+    cost: CostFn<'a, T>,
+}
+
+impl<'a, T: Copy + Display + PartialEq> HexGridGraph<'a, T> {
+    pub fn new(grid: &'a Grid<T>, passable: impl Fn(&T) -> bool + 'a) -> Self {
+        Self {
+            grid,
+            passable: Box::new(passable),
+            cost: Box::new(|_| 1),
+        }
+    }
+
+    /// The cost of moving into a cell with the given value
+    pub fn cost(mut self, cost: impl Fn(&T) -> usize + 'a) -> Self {
+        self.cost = Box::new(cost);
+        self
+    }
+}
+
+impl<T: Copy + Display + PartialEq> DijkstraSearchable for HexGridGraph<'_, T> {
+    type Node = Hex<isize>;
+    type Cost = usize;
+
+    fn neighbors(&self, previous: &Hex<isize>, previous_cost: usize) -> Vec<(Hex<isize>, usize)> {
+        previous
+            .neighbors()
+            .into_iter()
+            .filter_map(|hex| {
+                let coord = Coord2D::<usize>::try_from(hex.to_offset()).ok()?;
+                let val = self.grid.get(&coord)?;
+                (self.passable)(&val).then(|| (hex, previous_cost + (self.cost)(&val)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use utils::{shortest_path_length, shortest_paths};
+
+    use super::*;
+
+    #[test]
+    fn test_grid_graph() {
+        let grid = Grid::from_line_iter(
+            ["....", ".##.", ".9..", "...."]
+                .into_iter()
+                .map(|x| x.to_string()),
+        );
+        let start = Coord2D::new(0, 0);
+        let end = HashSet::from([Coord2D::new(3, 3)]);
+
+        let graph = GridGraph::new(&grid, |c| *c != '#');
+        assert_eq!(shortest_path_length(graph, start, end.clone()), Some(6));
+
+        // the cheapest path now has to go around the 9
+        let weighted = GridGraph::new(&grid, |c| *c != '#').cost(|c| match c {
+            '9' => 9,
+            _ => 1,
+        });
+        let (length, paths) = shortest_paths(weighted, start, end.clone()).unwrap();
+        assert_eq!(length, 6);
+        assert_eq!(paths.len(), 2);
+
+        let diagonal = GridGraph::new(&grid, |c| *c == '.').connectivity(Connectivity::Eight);
+        assert_eq!(shortest_path_length(diagonal, start, end), Some(5));
+    }
+
+    #[test]
+    fn test_heading_grid_graph() {
+        let grid = Grid::from_line_iter(
+            ["....", ".##.", ".9..", "...."]
+                .into_iter()
+                .map(|x| x.to_string()),
+        );
+        let start = (Coord2D::new(0, 0), CardinalDirection::East);
+        let ends: HashSet<_> = CardinalDirection::ALL
+            .into_iter()
+            .map(|d| (Coord2D::new(3, 3), d))
+            .collect();
+
+        // 6 steps and a single turn
+        let graph = GridGraph::new(&grid, |c| *c != '#').headings(1000);
+        assert_eq!(shortest_path_length(graph, start, ends), Some(1006));
+    }
+
+    #[test]
+    fn test_heading_turn_costs() {
+        // a dead end: facing east at the end of the corridor, the only way
+        // out is to turn around
+        let grid = Grid::from_line_iter(["#...#"].into_iter().map(|x| x.to_string()));
+        let start = (Coord2D::new(0, 3), CardinalDirection::East);
+
+        let graph = GridGraph::new(&grid, |c| *c != '#').headings(1000);
+        let mut turns = graph.neighbors(&start, 0);
+        turns.sort_by_key(|((_, heading), _)| heading.index());
+        assert_eq!(
+            turns,
+            vec![
+                ((Coord2D::new(0, 3), CardinalDirection::North), 1000),
+                ((Coord2D::new(0, 3), CardinalDirection::South), 1000),
+                ((Coord2D::new(0, 3), CardinalDirection::West), 2000),
+            ]
+        );
+
+        let end = HashSet::from([(Coord2D::new(0, 1), CardinalDirection::West)]);
+        assert_eq!(shortest_path_length(graph, start, end), Some(2002));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "four cardinal directions")]
+    fn test_heading_grid_graph_needs_four_connectivity() {
+        let grid = Grid::from_line_iter(["..", ".."].into_iter().map(|x| x.to_string()));
+        let _ = GridGraph::new(&grid, |_| true)
+            .connectivity(Connectivity::Eight)
+            .headings(1000);
+    }
+
+    #[test]
+    fn test_hex_grid_graph() {
+        // odd rows are shoved half a cell east:
+        //  . # . .
+        //   . # . .
+        //  . . . #
+        let grid =
+            Grid::from_line_iter([".#..", ".#..", "...#"].into_iter().map(|x| x.to_string()));
+        let start = Hex::from_offset(&Coord2D::new(0, 0));
+        let end = Hex::from_offset(&Coord2D::new(0, 2));
+
+        let graph = HexGridGraph::new(&grid, |c| *c != '#');
+        assert_eq!(
+            shortest_path_length(graph, start, HashSet::from([end])),
+            Some(5)
+        );
+    }
+}
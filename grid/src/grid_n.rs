@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use coord_2d::CoordN;
+
+/// A dense grid with any number of dimensions, stored as a single flat
+/// vector in row-major order (the last axis varies fastest)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridN<T: Copy + Display + PartialEq, const D: usize> {
+    inner: Vec<T>,
+    pub dims: [usize; D],
+}
+
+impl<T: Copy + Display + PartialEq, const D: usize> GridN<T, D> {
+    /// A grid of the given size along each axis, every cell set to fill
+    pub fn new(dims: [usize; D], fill: T) -> Self {
+        Self {
+            inner: vec![fill; dims.iter().product()],
+            dims,
+        }
+    }
+
+    /// Extrude a 2D grid of rows into D dimensions (e.g. the z = 0 slice
+    /// of a 3D grid); every other cell is set to fill. D must be at least 2.
+    pub fn from_plane(rows: Vec<Vec<T>>, dims: [usize; D], fill: T) -> Self {
+        let mut grid = Self::new(dims, fill);
+        for (row_idx, row) in rows.into_iter().enumerate() {
+            for (col_idx, val) in row.into_iter().enumerate() {
+                let mut coord = [0; D];
+                coord[0] = row_idx;
+                coord[1] = col_idx;
+                grid.set(val, &CoordN::new(coord));
+            }
+        }
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn contains(&self, coord: &CoordN<usize, D>) -> bool {
+        coord.0.iter().zip(self.dims).all(|(x, dim)| *x < dim)
+    }
+
+    fn index(&self, coord: &CoordN<usize, D>) -> Option<usize> {
+        if !self.contains(coord) {
+            return None;
+        }
+        Some(
+            coord
+                .0
+                .iter()
+                .zip(self.dims)
+                .fold(0, |idx, (x, dim)| idx * dim + x),
+        )
+    }
+
+    fn coord(&self, mut idx: usize) -> CoordN<usize, D> {
+        let mut coord = [0; D];
+        for axis in (0..D).rev() {
+            coord[axis] = idx % self.dims[axis];
+            idx /= self.dims[axis];
+        }
+        CoordN::new(coord)
+    }
+
+    pub fn get(&self, coord: &CoordN<usize, D>) -> Option<T> {
+        self.index(coord).map(|idx| self.inner[idx])
+    }
+
+    /// Panics if coord is off the grid
+    pub fn set(&mut self, val: T, coord: &CoordN<usize, D>) {
+        let idx = self
+            .index(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside a grid of size {:?}", coord, self.dims));
+        self.inner[idx] = val;
+    }
+
+    pub fn find(&self, needle: T) -> HashSet<CoordN<usize, D>> {
+        self.coords_and_vals()
+            .filter(|(_, val)| *val == needle)
+            .map(|(coord, _)| coord)
+            .collect()
+    }
+
+    pub fn coords_and_vals(&self) -> impl Iterator<Item = (CoordN<usize, D>, T)> + use<'_, T, D> {
+        self.inner
+            .iter()
+            .enumerate()
+            .map(|(idx, val)| (self.coord(idx), *val))
+    }
+
+    /// Every neighbor of coord (see `CoordN::neighbors`) that is on the grid, with its value
+    pub fn neighbors(&self, coord: &CoordN<usize, D>) -> Vec<(CoordN<usize, D>, T)> {
+        coord
+            .neighbors()
+            .into_iter()
+            .filter_map(|neighbor| self.get(&neighbor).map(|val| (neighbor, val)))
+            .collect()
+    }
+
+    /// Like `GridN::neighbors`, but only along a single axis
+    pub fn cardinal_neighbors(&self, coord: &CoordN<usize, D>) -> Vec<(CoordN<usize, D>, T)> {
+        coord
+            .cardinal_neighbors()
+            .into_iter()
+            .filter_map(|neighbor| self.get(&neighbor).map(|val| (neighbor, val)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_n() {
+        let mut grid: GridN<char, 3> = GridN::from_plane(
+            vec![vec!['.', '#', '.'], vec!['.', '.', '#']],
+            [2, 3, 4],
+            '.',
+        );
+        assert_eq!(grid.len(), 24);
+        assert_eq!(
+            grid.find('#'),
+            HashSet::from([CoordN::new([0, 1, 0]), CoordN::new([1, 2, 0])])
+        );
+
+        grid.set('#', &CoordN::new([1, 2, 3]));
+        assert_eq!(grid.get(&CoordN::new([1, 2, 3])), Some('#'));
+        assert_eq!(grid.get(&CoordN::new([2, 0, 0])), None);
+
+        let corner = CoordN::new([0, 0, 0]);
+        assert_eq!(grid.neighbors(&corner).len(), 7);
+        assert_eq!(
+            grid.cardinal_neighbors(&corner)
+                .into_iter()
+                .filter(|(_, val)| *val == '#')
+                .count(),
+            1
+        );
+        assert_eq!(
+            grid.neighbors(&CoordN::new([1, 1, 1]))
+                .into_iter()
+                .filter(|(_, val)| *val == '#')
+                .count(),
+            2
+        );
+
+        let coords: Vec<_> = grid.coords_and_vals().map(|(coord, _)| coord).collect();
+        assert_eq!(coords[5], CoordN::new([0, 1, 1]));
+        assert!(coords.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io;
+
+use thiserror::Error;
+
+use coord_2d::Coord2D;
+
+use crate::Grid;
+
+pub type Rgb = [u8; 3];
+
+#[derive(Error, Debug)]
+pub enum ImageError {
+    #[error("failed to write image")]
+    Io(#[from] io::Error),
+    #[error("failed to encode png")]
+    Png(#[from] png::EncodingError),
+    #[error("failed to encode gif")]
+    Gif(#[from] gif::EncodingError),
+    #[error("a gif frame can have at most 256 colors")]
+    TooManyColors,
+    #[error("a gif can be at most 65535 pixels on a side; found {0}x{1}")]
+    TooLarge(usize, usize),
+    #[error("every frame of a gif must be {expected:?} pixels; found {found:?}")]
+    FrameSizeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+/// The color to draw each value of a grid
+#[derive(Debug, Clone)]
+pub struct Palette<T: PartialEq> {
+    colors: Vec<(T, Rgb)>,
+    default: Rgb,
+}
+
+impl<T: PartialEq> Palette<T> {
+    /// A palette that draws every value in the default color
+    pub fn new(default: Rgb) -> Self {
+        Self {
+            colors: Vec::new(),
+            default,
+        }
+    }
+
+    pub fn with(mut self, val: T, color: Rgb) -> Self {
+        self.colors.push((val, color));
+        self
+    }
+
+    pub fn color(&self, val: &T) -> Rgb {
+        self.colors
+            .iter()
+            .find(|(v, _)| v == val)
+            .map(|(_, color)| *color)
+            .unwrap_or(self.default)
+    }
+}
+
+/// An RGB raster, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Write a binary (P6) portable pixmap
+    pub fn write_ppm(&self, out: &mut impl io::Write) -> Result<(), ImageError> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(self.pixels.as_flattened())?;
+        Ok(())
+    }
+
+    pub fn write_png(&self, out: impl io::Write) -> Result<(), ImageError> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(self.pixels.as_flattened())?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// The frame's pixels as indices into a palette of its distinct colors
+    fn to_gif_frame(&self) -> Result<gif::Frame<'static>, ImageError> {
+        let mut palette: Vec<Rgb> = Vec::new();
+        let mut palette_idx: HashMap<Rgb, u8> = HashMap::new();
+        let mut indices: Vec<u8> = Vec::with_capacity(self.pixels.len());
+        for pixel in self.pixels.iter() {
+            let idx = match palette_idx.get(pixel) {
+                Some(idx) => *idx,
+                None => {
+                    if palette.len() == 256 {
+                        return Err(ImageError::TooManyColors);
+                    }
+                    let idx = palette.len() as u8;
+                    palette.push(*pixel);
+                    palette_idx.insert(*pixel, idx);
+                    idx
+                }
+            };
+            indices.push(idx);
+        }
+
+        Ok(gif::Frame::from_palette_pixels(
+            self.width as u16,
+            self.height as u16,
+            indices,
+            palette.as_flattened(),
+            None,
+        ))
+    }
+}
+
+/// Draws a grid, plus any number of overlays, as an image where each
+/// cell is a `scale` x `scale` square of pixels
+pub struct Rasterizer<'a, T: Copy + Display + PartialEq> {
+    grid: &'a Grid<T>,
+    palette: &'a Palette<T>,
+    overlays: Vec<(Vec<Coord2D<usize>>, Rgb)>,
+    scale: usize,
+}
+
+impl<'a, T: Copy + Display + PartialEq> Rasterizer<'a, T> {
+    pub fn new(grid: &'a Grid<T>, palette: &'a Palette<T>) -> Self {
+        Self {
+            grid,
+            palette,
+            overlays: Vec::new(),
+            scale: 1,
+        }
+    }
+
+    /// Color every coord; later overlays cover earlier ones
+    pub fn overlay(mut self, coords: impl IntoIterator<Item = Coord2D<usize>>, color: Rgb) -> Self {
+        self.overlays.push((coords.into_iter().collect(), color));
+        self
+    }
+
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn rasterize(&self) -> Image {
+        let mut cells: Vec<Rgb> = self
+            .grid
+            .coords_and_vals::<usize>()
+            .map(|(_, val)| self.palette.color(&val))
+            .collect();
+        for (coords, color) in self.overlays.iter() {
+            for coord in coords {
+                if coord.row < self.grid.n_rows && coord.col < self.grid.n_cols {
+                    cells[coord.row * self.grid.n_cols + coord.col] = *color;
+                }
+            }
+        }
+
+        let width = self.grid.n_cols * self.scale;
+        let height = self.grid.n_rows * self.scale;
+        let pixels: Vec<Rgb> = (0..height)
+            .flat_map(|y| {
+                let cells = &cells;
+                (0..width).map(move |x| cells[(y / self.scale) * self.grid.n_cols + x / self.scale])
+            })
+            .collect();
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// Records a sequence of images (e.g. the states of a simulation)
+/// as a looping animated gif
+pub struct GifRecorder<W: io::Write> {
+    encoder: gif::Encoder<W>,
+    size: (usize, usize),
+    /// How long each frame is shown, in hundredths of a second
+    delay: u16,
+}
+
+impl<W: io::Write> GifRecorder<W> {
+    pub fn new(out: W, width: usize, height: usize, delay: u16) -> Result<Self, ImageError> {
+        if width > u16::MAX as usize || height > u16::MAX as usize {
+            return Err(ImageError::TooLarge(width, height));
+        }
+
+        let mut encoder = gif::Encoder::new(out, width as u16, height as u16, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        Ok(Self {
+            encoder,
+            size: (width, height),
+            delay,
+        })
+    }
+
+    pub fn add_frame(&mut self, image: &Image) -> Result<(), ImageError> {
+        if (image.width, image.height) != self.size {
+            return Err(ImageError::FrameSizeMismatch {
+                expected: self.size,
+                found: (image.width, image.height),
+            });
+        }
+
+        let mut frame = image.to_gif_frame()?;
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame)?;
+        Ok(())
+    }
+
+    /// Finish the gif, returning the underlying writer
+    pub fn finish(self) -> Result<W, ImageError> {
+        Ok(self.encoder.into_inner()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rasterize() {
+        let palette = Palette::new([255, 255, 255]).with('#', [0, 0, 0]);
+        let grid = Grid::from_line_iter(["#.", ".#"].into_iter().map(|x| x.to_string()));
+        let image = Rasterizer::new(&grid, &palette)
+            .overlay([Coord2D::new(0, 1)], [255, 0, 0])
+            .scale(2)
+            .rasterize();
+
+        assert_eq!((image.width, image.height), (4, 4));
+        assert_eq!(image.pixel(1, 1), [0, 0, 0]);
+        assert_eq!(image.pixel(3, 0), [255, 0, 0]);
+        assert_eq!(image.pixel(0, 3), [255, 255, 255]);
+
+        let mut ppm: Vec<u8> = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let mut png: Vec<u8> = Vec::new();
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn test_gif_recorder() {
+        let palette = Palette::new([255, 255, 255]).with('#', [0, 0, 0]);
+        let mut grid = Grid::from_line_iter(["#.", ".#"].into_iter().map(|x| x.to_string()));
+        let mut recorder = GifRecorder::new(Vec::new(), 2, 2, 10).unwrap();
+        for _ in 0..3 {
+            recorder
+                .add_frame(&Rasterizer::new(&grid, &palette).rasterize())
+                .unwrap();
+            grid.set('#', 0, 1);
+        }
+
+        let too_big = Rasterizer::new(&grid, &palette).scale(2).rasterize();
+        assert!(matches!(
+            recorder.add_frame(&too_big),
+            Err(ImageError::FrameSizeMismatch { .. })
+        ));
+
+        let gif = recorder.finish().unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+    }
+}
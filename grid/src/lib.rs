@@ -9,6 +9,34 @@ use num::Integer;
 
 use coord_2d::Coord2D;
 
+mod bit_grid;
+mod connectivity;
+mod geometry;
+mod graph;
+mod grid_n;
+mod image;
+mod neighbors;
+mod ray;
+mod region;
+mod render;
+mod serialize;
+mod sparse;
+mod tile;
+mod union_find;
+
+pub use bit_grid::BitGrid;
+pub use connectivity::Connectivity;
+pub use geometry::{boundary_polygons, Polygon};
+pub use graph::{GridGraph, HeadingGridGraph, HexGridGraph};
+pub use grid_n::GridN;
+pub use image::{GifRecorder, Image, ImageError, Palette, Rasterizer, Rgb};
+pub use neighbors::Neighbors;
+pub use ray::{Bresenham, Ray};
+pub use region::Region;
+pub use render::{Color, Renderer};
+pub use sparse::SparseGrid;
+pub use tile::{ParseGridError, Tile};
+
 #[derive(Clone, Debug)]
 pub struct Grid<T: Copy + Display + PartialEq> {
     inner: Vec<Vec<T>>,
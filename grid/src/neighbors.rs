@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use coord_2d::Coord2D;
+
+use crate::connectivity::Connectivity;
+use crate::Grid;
+
+/// The neighbors of a cell that lie on the grid, along with their values.
+/// Created by `Grid::neighbors` and `Grid::neighbors_wrapping`.
+pub struct Neighbors<'a, T: Copy + Display + PartialEq> {
+    grid: &'a Grid<T>,
+    row: usize,
+    col: usize,
+    offsets: std::slice::Iter<'static, (isize, isize)>,
+    wrap: bool,
+}
+
+/// Shift an index by an offset; None if it leaves the range 0..len
+/// (or, when wrapping, wrap it back around)
+fn shift(idx: usize, offset: isize, len: usize, wrap: bool) -> Option<usize> {
+    if wrap {
+        Some((idx as isize + offset).rem_euclid(len as isize) as usize)
+    } else {
+        idx.checked_add_signed(offset)
+            .filter(|shifted| *shifted < len)
+    }
+}
+
+impl<T: Copy + Display + PartialEq> Iterator for Neighbors<'_, T> {
+    type Item = (Coord2D<usize>, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (d_row, d_col) in self.offsets.by_ref() {
+            let row = shift(self.row, *d_row, self.grid.n_rows, self.wrap);
+            let col = shift(self.col, *d_col, self.grid.n_cols, self.wrap);
+            if let (Some(row), Some(col)) = (row, col) {
+                return Some((Coord2D::new(row, col), self.grid.inner[row][col]));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
+impl<T: Copy + Display + PartialEq> Grid<T> {
+    /// Every neighbor of coord that is on the grid, with its value
+    pub fn neighbors(
+        &self,
+        coord: &Coord2D<usize>,
+        connectivity: Connectivity,
+    ) -> Neighbors<'_, T> {
+        Neighbors {
+            grid: self,
+            row: coord.row,
+            col: coord.col,
+            offsets: connectivity.offsets().iter(),
+            wrap: false,
+        }
+    }
+
+    /// Every neighbor of coord, with its value, where stepping off
+    /// one edge of the grid wraps around to the opposite edge
+    pub fn neighbors_wrapping(
+        &self,
+        coord: &Coord2D<usize>,
+        connectivity: Connectivity,
+    ) -> Neighbors<'_, T> {
+        Neighbors {
+            grid: self,
+            row: coord.row,
+            col: coord.col,
+            offsets: connectivity.offsets().iter(),
+            wrap: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::from_line_iter(["abc", "def", "ghi"].into_iter().map(|x| x.to_string()));
+
+        let vals = |neighbors: Neighbors<'_, char>| neighbors.map(|(_, v)| v).collect::<String>();
+        assert_eq!(
+            vals(grid.neighbors(&Coord2D::new(1, 1), Connectivity::Four)),
+            "bfhd"
+        );
+        assert_eq!(
+            vals(grid.neighbors(&Coord2D::new(0, 0), Connectivity::Eight)),
+            "bed"
+        );
+        assert_eq!(
+            vals(grid.neighbors_wrapping(&Coord2D::new(0, 0), Connectivity::Four)),
+            "gbdc"
+        );
+
+        const KNIGHT: [(isize, isize); 8] = [
+            (-2, -1),
+            (-2, 1),
+            (-1, 2),
+            (1, 2),
+            (2, 1),
+            (2, -1),
+            (1, -2),
+            (-1, -2),
+        ];
+        assert_eq!(
+            grid.neighbors(&Coord2D::new(0, 0), Connectivity::Custom(&KNIGHT))
+                .collect::<Vec<_>>(),
+            vec![(Coord2D::new(1, 2), 'f'), (Coord2D::new(2, 1), 'h')]
+        );
+    }
+}
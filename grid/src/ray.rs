@@ -0,0 +1,208 @@
+use std::fmt::Display;
+
+use coord_2d::Coord2D;
+use direction::CardinalDirection;
+
+use crate::Grid;
+
+/// The cells visited by repeatedly stepping from a starting coord, along with
+/// their values; ends when the next step would leave the grid. Created by
+/// `Grid::ray` and `Grid::ray_towards`.
+pub struct Ray<'a, T: Copy + Display + PartialEq> {
+    grid: &'a Grid<T>,
+    current: Coord2D<isize>,
+    step: Coord2D<isize>,
+}
+
+impl<T: Copy + Display + PartialEq> Iterator for Ray<'_, T> {
+    type Item = (Coord2D<usize>, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step.row == 0 && self.step.col == 0 {
+            return None;
+        }
+
+        let next = self.current + self.step;
+        let coord = self.grid.to_grid_coord(&next)?;
+        self.current = next;
+        Some((coord, self.grid.inner[coord.row][coord.col]))
+    }
+}
+
+/// The lattice points on the straight line between two coords (inclusive),
+/// as drawn by Bresenham's algorithm
+pub struct Bresenham {
+    current: Coord2D<isize>,
+    end: Coord2D<isize>,
+    d_row: isize,
+    d_col: isize,
+    step_row: isize,
+    step_col: isize,
+    error: isize,
+    done: bool,
+}
+
+impl Bresenham {
+    pub fn new(start: &Coord2D<isize>, end: &Coord2D<isize>) -> Self {
+        let d_row = -(end.row - start.row).abs();
+        let d_col = (end.col - start.col).abs();
+        Self {
+            current: *start,
+            end: *end,
+            d_row,
+            d_col,
+            step_row: (end.row - start.row).signum(),
+            step_col: (end.col - start.col).signum(),
+            error: d_col + d_row,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = Coord2D<isize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.current;
+        if self.current == self.end {
+            self.done = true;
+            return Some(result);
+        }
+
+        let doubled_error = 2 * self.error;
+        if doubled_error >= self.d_row {
+            self.error += self.d_row;
+            self.current.col += self.step_col;
+        }
+        if doubled_error <= self.d_col {
+            self.error += self.d_col;
+            self.current.row += self.step_row;
+        }
+        Some(result)
+    }
+}
+
+impl<T: Copy + Display + PartialEq> Grid<T> {
+    /// Convert a signed coord to a coord on the grid; None if it's off the grid
+    fn to_grid_coord(&self, coord: &Coord2D<isize>) -> Option<Coord2D<usize>> {
+        let coord = Coord2D::<usize>::try_from(*coord).ok()?;
+        if coord.row < self.n_rows && coord.col < self.n_cols {
+            Some(coord)
+        } else {
+            None
+        }
+    }
+
+    /// Step from start (exclusive) by `step` until leaving the grid. To also
+    /// stop at some other condition, use `take_while`; see also `Grid::first_hit`.
+    pub fn ray(&self, start: &Coord2D<usize>, step: Coord2D<isize>) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            current: Coord2D::new(start.row as isize, start.col as isize),
+            step,
+        }
+    }
+
+    /// Walk from start (exclusive) in a direction until leaving the grid
+    pub fn ray_towards(&self, start: &Coord2D<usize>, direction: &CardinalDirection) -> Ray<'_, T> {
+        self.ray(start, Coord2D::from(*direction))
+    }
+
+    /// The first cell along a ray whose value satisfies `hit`
+    pub fn first_hit<F>(
+        &self,
+        start: &Coord2D<usize>,
+        step: Coord2D<isize>,
+        hit: F,
+    ) -> Option<(Coord2D<usize>, T)>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.ray(start, step).find(|(_, val)| hit(val))
+    }
+
+    /// The cells on the straight line from start to end (inclusive) that are
+    /// on the grid, with their values
+    pub fn line(
+        &self,
+        start: &Coord2D<usize>,
+        end: &Coord2D<usize>,
+    ) -> impl Iterator<Item = (Coord2D<usize>, T)> + use<'_, T> {
+        Bresenham::new(
+            &Coord2D::new(start.row as isize, start.col as isize),
+            &Coord2D::new(end.row as isize, end.col as isize),
+        )
+        .filter_map(|coord| self.to_grid_coord(&coord))
+        .map(|coord| {
+            let val = self.inner[coord.row][coord.col];
+            (coord, val)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::from_line_iter(
+            ["abcde", "fghij", "klmno", "pqrst"]
+                .into_iter()
+                .map(|x| x.to_string()),
+        );
+        let vals = |ray: Ray<'_, char>| ray.map(|(_, v)| v).collect::<String>();
+
+        assert_eq!(
+            vals(grid.ray(&Coord2D::new(0, 0), Coord2D::new(1, 2))),
+            "ho"
+        );
+        assert_eq!(
+            vals(grid.ray_towards(&Coord2D::new(2, 2), &CardinalDirection::West)),
+            "lk"
+        );
+        assert_eq!(vals(grid.ray(&Coord2D::new(2, 2), Coord2D::new(0, 0))), "");
+
+        assert_eq!(
+            grid.first_hit(&Coord2D::new(3, 4), Coord2D::new(-1, -1), |c| *c < 'n'),
+            Some((Coord2D::new(1, 2), 'h'))
+        );
+        assert_eq!(
+            grid.first_hit(&Coord2D::new(3, 4), Coord2D::new(-1, 0), |c| *c == 'z'),
+            None
+        );
+    }
+
+    #[test]
+    fn test_line() {
+        let grid = Grid::from_line_iter(
+            ["abcde", "fghij", "klmno", "pqrst"]
+                .into_iter()
+                .map(|x| x.to_string()),
+        );
+        assert_eq!(
+            grid.line(&Coord2D::new(0, 0), &Coord2D::new(3, 4))
+                .map(|(_, v)| v)
+                .collect::<String>(),
+            "agmnt"
+        );
+        assert_eq!(
+            grid.line(&Coord2D::new(3, 0), &Coord2D::new(3, 2))
+                .map(|(_, v)| v)
+                .collect::<String>(),
+            "pqr"
+        );
+        assert_eq!(
+            Bresenham::new(&Coord2D::new(0, 0), &Coord2D::new(-2, -1)).collect::<Vec<_>>(),
+            vec![
+                Coord2D::new(0, 0),
+                Coord2D::new(-1, -1),
+                Coord2D::new(-2, -1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use coord_2d::Coord2D;
+
+use crate::connectivity::Connectivity;
+use crate::geometry::{boundary_polygons, Polygon};
+use crate::union_find::UnionFind;
+use crate::Grid;
+
+/// A connected group of grid cells
+#[derive(Debug, Clone)]
+pub struct Region<T> {
+    /// The index of this region in the result of `Grid::regions_by`
+    pub label: usize,
+    /// The value of the first cell of the region (in row-major order)
+    pub value: T,
+    pub coords: HashSet<Coord2D<usize>>,
+}
+
+impl<T> Region<T> {
+    pub fn contains(&self, coord: &Coord2D<usize>) -> bool {
+        self.coords.contains(coord)
+    }
+
+    pub fn area(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// The number of cell edges that separate a member of the region
+    /// from a cell that isn't a member (including the edge of the grid)
+    pub fn perimeter(&self) -> usize {
+        self.coords
+            .iter()
+            .map(|coord| {
+                Connectivity::Four
+                    .offsets()
+                    .iter()
+                    .filter(|(d_row, d_col)| !self.is_member(coord, *d_row, *d_col))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The (top left, bottom right) corners of the smallest rectangle
+    /// containing every member of the region (inclusive)
+    pub fn bounding_box(&self) -> (Coord2D<usize>, Coord2D<usize>) {
+        let min_row = self.coords.iter().map(|c| c.row).min().unwrap();
+        let max_row = self.coords.iter().map(|c| c.row).max().unwrap();
+        let min_col = self.coords.iter().map(|c| c.col).min().unwrap();
+        let max_col = self.coords.iter().map(|c| c.col).max().unwrap();
+
+        (
+            Coord2D::new(min_row, min_col),
+            Coord2D::new(max_row, max_col),
+        )
+    }
+
+    /// The number of straight sides of the region's boundary (including
+    /// the boundaries of any holes). This is equal to the number of corners.
+    ///
+    /// Each cell is checked at its four corners: looking diagonally out from
+    /// the cell, the corner is convex if neither orthogonal neighbor is a member
+    /// and concave if both orthogonal neighbors are members but the diagonal isn't.
+    pub fn n_sides(&self) -> usize {
+        const DIAGONALS: [(isize, isize); 4] = [(-1, 1), (1, 1), (1, -1), (-1, -1)];
+
+        self.coords
+            .iter()
+            .map(|coord| {
+                DIAGONALS
+                    .iter()
+                    .filter(|(d_row, d_col)| {
+                        let vertical = self.is_member(coord, *d_row, 0);
+                        let horizontal = self.is_member(coord, 0, *d_col);
+                        let diagonal = self.is_member(coord, *d_row, *d_col);
+
+                        (!vertical && !horizontal) || (vertical && horizontal && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The outer boundary of the region and the boundaries of any holes in it
+    pub fn polygons(&self) -> Vec<Polygon> {
+        boundary_polygons(&self.coords)
+    }
+
+    fn is_member(&self, coord: &Coord2D<usize>, d_row: isize, d_col: isize) -> bool {
+        match (
+            coord.row.checked_add_signed(d_row),
+            coord.col.checked_add_signed(d_col),
+        ) {
+            (Some(row), Some(col)) => self.coords.contains(&Coord2D::new(row, col)),
+            _ => false,
+        }
+    }
+}
+
+impl<T: Copy + Display + PartialEq> Grid<T> {
+    /// Partition the grid into regions of touching cells with equal values
+    pub fn regions(&self, connectivity: Connectivity) -> Vec<Region<T>> {
+        self.regions_by(connectivity, |a, b| a == b)
+    }
+
+    /// Partition the grid into regions; two touching cells belong to the same
+    /// region if `same_region` returns true for their values. Regions are
+    /// labelled in row-major order of their first cell.
+    pub fn regions_by<F>(&self, connectivity: Connectivity, same_region: F) -> Vec<Region<T>>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let idx = |row: usize, col: usize| row * self.n_cols + col;
+
+        let mut sets = UnionFind::new(self.n_rows * self.n_cols);
+        for row in 0..self.n_rows {
+            for col in 0..self.n_cols {
+                let val = &self.inner[row][col];
+                for (d_row, d_col) in connectivity.forward_offsets() {
+                    let (Some(n_row), Some(n_col)) = (
+                        row.checked_add_signed(*d_row),
+                        col.checked_add_signed(*d_col),
+                    ) else {
+                        continue;
+                    };
+                    if n_row >= self.n_rows || n_col >= self.n_cols {
+                        continue;
+                    }
+
+                    if same_region(val, &self.inner[n_row][n_col]) {
+                        sets.union(idx(row, col), idx(n_row, n_col));
+                    }
+                }
+            }
+        }
+
+        // the label assigned to each root of the forest
+        let mut labels: Vec<Option<usize>> = vec![None; self.n_rows * self.n_cols];
+        let mut result: Vec<Region<T>> = Vec::new();
+        for row in 0..self.n_rows {
+            for col in 0..self.n_cols {
+                let root = sets.find(idx(row, col));
+                let label = match labels[root] {
+                    Some(label) => label,
+                    None => {
+                        let label = result.len();
+                        labels[root] = Some(label);
+                        result.push(Region {
+                            label,
+                            value: self.inner[row][col],
+                            coords: HashSet::new(),
+                        });
+                        label
+                    }
+                };
+                result[label].coords.insert(Coord2D::new(row, col));
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[&str]) -> Grid<char> {
+        Grid::from_line_iter(rows.iter().map(|x| x.to_string()))
+    }
+
+    #[test]
+    fn test_regions() {
+        let regions = grid(&["AAAA", "BBCD", "BBCC", "EEEC"]).regions(Connectivity::Four);
+
+        assert_eq!(
+            regions
+                .iter()
+                .map(|r| (r.value, r.area(), r.perimeter(), r.n_sides()))
+                .collect::<Vec<_>>(),
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+        assert_eq!(
+            regions[2].bounding_box(),
+            (Coord2D::new(1, 2), Coord2D::new(3, 3))
+        );
+        assert_eq!(
+            regions[2]
+                .polygons()
+                .iter()
+                .map(|p| p.n_sides())
+                .collect::<Vec<_>>(),
+            vec![8]
+        );
+    }
+
+    #[test]
+    fn test_regions_connectivity() {
+        let checkerboard = grid(&["X.X", ".X.", "X.X"]);
+        assert_eq!(checkerboard.regions(Connectivity::Four).len(), 9);
+
+        let regions = checkerboard.regions(Connectivity::Eight);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].area(), 5);
+        assert_eq!(regions[1].area(), 4);
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io;
+
+use coord_2d::Coord2D;
+
+use crate::Grid;
+
+/// The standard 16 terminal colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl Color {
+    /// The SGR code that sets the foreground to this color
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::BrightBlack => 90,
+            Color::BrightRed => 91,
+            Color::BrightGreen => 92,
+            Color::BrightYellow => 93,
+            Color::BrightBlue => 94,
+            Color::BrightMagenta => 95,
+            Color::BrightCyan => 96,
+            Color::BrightWhite => 97,
+        }
+    }
+}
+
+type ColorFn<'a, T> = Box<dyn Fn(&T) -> Option<Color> + 'a>;
+
+/// A set of cells drawn on top of the grid
+struct Overlay {
+    glyphs: HashMap<Coord2D<usize>, String>,
+    color: Option<Color>,
+}
+
+/// Draws a grid, plus any number of overlays, to an `io::Write`:
+///
+/// ```
+/// use coord_2d::Coord2D;
+/// use grid::{Color, Grid, Renderer};
+///
+/// let grid = Grid::from_line_iter(["#...", "#..#"].into_iter().map(|x| x.to_string()));
+/// let path = [Coord2D::new(0, 1), Coord2D::new(0, 2), Coord2D::new(1, 2)];
+/// let rendered = Renderer::new(&grid).overlay(path, 'O', Some(Color::Red)).render();
+/// assert_eq!(rendered, "#OO.\n#.O#\n");
+/// ```
+///
+/// Overlays are drawn in the order they're added, so later overlays
+/// cover earlier ones.
+pub struct Renderer<'a, T: Copy + Display + PartialEq> {
+    grid: &'a Grid<T>,
+    overlays: Vec<Overlay>,
+    base_color: Option<ColorFn<'a, T>>,
+    ansi: bool,
+    rulers: bool,
+    window: Option<(Coord2D<usize>, Coord2D<usize>)>,
+}
+
+impl<'a, T: Copy + Display + PartialEq> Renderer<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            overlays: Vec::new(),
+            base_color: None,
+            ansi: false,
+            rulers: false,
+            window: None,
+        }
+    }
+
+    /// Draw `glyph` at every coord
+    pub fn overlay(
+        self,
+        coords: impl IntoIterator<Item = Coord2D<usize>>,
+        glyph: char,
+        color: Option<Color>,
+    ) -> Self {
+        self.overlay_with(coords.into_iter().map(|c| (c, glyph)), color)
+    }
+
+    /// Draw a different glyph for each coord
+    pub fn overlay_with<G: Display>(
+        mut self,
+        glyphs: impl IntoIterator<Item = (Coord2D<usize>, G)>,
+        color: Option<Color>,
+    ) -> Self {
+        self.overlays.push(Overlay {
+            glyphs: glyphs
+                .into_iter()
+                .map(|(coord, glyph)| (coord, glyph.to_string()))
+                .collect(),
+            color,
+        });
+        self
+    }
+
+    /// Choose a color for each cell of the underlying grid
+    pub fn base_color(mut self, color: impl Fn(&T) -> Option<Color> + 'a) -> Self {
+        self.base_color = Some(Box::new(color));
+        self
+    }
+
+    /// Emit ANSI escape codes for colored cells (off by default)
+    pub fn ansi(mut self, ansi: bool) -> Self {
+        self.ansi = ansi;
+        self
+    }
+
+    /// Label rows down the left side and columns across the top
+    pub fn rulers(mut self, rulers: bool) -> Self {
+        self.rulers = rulers;
+        self
+    }
+
+    /// Only draw the cells between the two corners (inclusive)
+    pub fn crop(mut self, top_left: Coord2D<usize>, bottom_right: Coord2D<usize>) -> Self {
+        self.window = Some((top_left, bottom_right));
+        self
+    }
+
+    /// The (top left, bottom right) corners of the area to draw
+    fn bounds(&self) -> (Coord2D<usize>, Coord2D<usize>) {
+        let max = Coord2D::new(self.grid.n_rows - 1, self.grid.n_cols - 1);
+        match &self.window {
+            Some((top_left, bottom_right)) => (
+                *top_left,
+                Coord2D::new(bottom_right.row.min(max.row), bottom_right.col.min(max.col)),
+            ),
+            None => (Coord2D::new(0, 0), max),
+        }
+    }
+
+    fn write_cell(&self, out: &mut impl io::Write, coord: &Coord2D<usize>) -> io::Result<()> {
+        let (glyph, color) = match self
+            .overlays
+            .iter()
+            .rev()
+            .find_map(|o| o.glyphs.get(coord).map(|g| (g.clone(), o.color)))
+        {
+            Some(drawn) => drawn,
+            None => {
+                let val = self.grid.get(coord).unwrap();
+                let color = self.base_color.as_ref().and_then(|f| f(&val));
+                (val.to_string(), color)
+            }
+        };
+
+        match color {
+            Some(color) if self.ansi => write!(out, "\x1b[{}m{}\x1b[0m", color.ansi_code(), glyph),
+            _ => write!(out, "{}", glyph),
+        }
+    }
+
+    pub fn write_to(&self, out: &mut impl io::Write) -> io::Result<()> {
+        let (top_left, bottom_right) = self.bounds();
+        let label_width = bottom_right.row.to_string().len();
+
+        if self.rulers {
+            // one line per digit of the column index, most significant first
+            let n_digits = bottom_right.col.to_string().len();
+            for place in (0..n_digits).rev() {
+                write!(out, "{} ", " ".repeat(label_width))?;
+                for col in top_left.col..=bottom_right.col {
+                    let divisor = 10usize.pow(place as u32);
+                    if place > 0 && col < divisor {
+                        write!(out, " ")?;
+                    } else {
+                        write!(out, "{}", (col / divisor) % 10)?;
+                    }
+                }
+                writeln!(out)?;
+            }
+        }
+
+        for row in top_left.row..=bottom_right.row {
+            if self.rulers {
+                write!(out, "{:>width$} ", row, width = label_width)?;
+            }
+            for col in top_left.col..=bottom_right.col {
+                self.write_cell(out, &Coord2D::new(row, col))?;
+            }
+            writeln!(out)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn render(&self) -> String {
+        let mut buffer: Vec<u8> = Vec::new();
+        self.write_to(&mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    pub fn print(&self) {
+        self.write_to(&mut io::stdout().lock()).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::from_line_iter(
+            ["...........", "...........", ".....#....."]
+                .into_iter()
+                .map(|x| x.to_string()),
+        );
+
+        let rendered = Renderer::new(&grid)
+            .overlay([Coord2D::new(0, 9), Coord2D::new(1, 9)], 'x', None)
+            .overlay_with([(Coord2D::new(1, 9), 2)], Some(Color::Green))
+            .rulers(true)
+            .crop(Coord2D::new(1, 4), Coord2D::new(5, 10))
+            .render();
+        assert_eq!(rendered, "        1\n  4567890\n1 .....2.\n2 .#.....\n");
+
+        let colored = Renderer::new(&grid)
+            .base_color(|c| if *c == '#' { Some(Color::Red) } else { None })
+            .ansi(true)
+            .crop(Coord2D::new(2, 4), Coord2D::new(2, 6))
+            .render();
+        assert_eq!(colored, ".\x1b[31m#\x1b[0m.\n");
+    }
+}
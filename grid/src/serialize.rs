@@ -0,0 +1,162 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::tile::{ParseGridError, Tile};
+use crate::Grid;
+
+/// One line per row. A tile with no single character form (like a multi-digit
+/// number) is written with its own `Display` instead, so use `Grid::try_render`
+/// when the result must parse back into an equal grid.
+impl<T: Tile> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row_idx, row) in self.inner.iter().enumerate() {
+            if row_idx > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                match tile.to_char() {
+                    Some(c) => write!(f, "{}", c)?,
+                    None => write!(f, "{}", tile)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Tile> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_line_iter(s.lines())
+    }
+}
+
+impl<T: Tile> Grid<T> {
+    /// One line per row, which parses back into an equal grid with `FromStr`;
+    /// None if a tile can't be drawn as a single character
+    pub fn try_render(&self) -> Option<String> {
+        let rows = self
+            .inner
+            .iter()
+            .map(|row| row.iter().map(|tile| tile.to_char()).collect())
+            .collect::<Option<Vec<String>>>()?;
+        Some(rows.join("\n"))
+    }
+
+    /// A run-length encoding of the grid: one line per row, where each run of
+    /// repeated tiles is written as the tile followed by the length of the run,
+    /// separated by commas (so "#3,.2" is "###.."). None if a tile can't be
+    /// drawn as a single character.
+    pub fn to_rle(&self) -> Option<String> {
+        let mut lines: Vec<String> = Vec::new();
+        for row in self.inner.iter() {
+            let mut runs: Vec<String> = Vec::new();
+            let mut tiles = row.iter().peekable();
+            while let Some(tile) = tiles.next() {
+                let mut run_length: usize = 1;
+                while tiles.next_if_eq(&tile).is_some() {
+                    run_length += 1;
+                }
+                runs.push(format!("{}{}", tile.to_char()?, run_length));
+            }
+            lines.push(runs.join(","));
+        }
+        Some(lines.join("\n"))
+    }
+
+    /// Parse the output of `Grid::to_rle`
+    pub fn from_rle(s: &str) -> Result<Self, ParseGridError> {
+        let mut lines: Vec<String> = Vec::new();
+        for (row, encoded) in s.lines().enumerate() {
+            let mut line = String::new();
+            let mut chars = encoded.chars().peekable();
+            let mut run: usize = 0;
+            // the glyph is always a single character, so it may be a digit or a comma
+            while let Some(glyph) = chars.next() {
+                let mut digits = String::new();
+                while let Some(d) = chars.next_if(|c| c.is_ascii_digit()) {
+                    digits.push(d);
+                }
+                let run_length: usize = digits
+                    .parse()
+                    .map_err(|_| ParseGridError::BadRun { row, run })?;
+                if !matches!(chars.next(), None | Some(',')) {
+                    return Err(ParseGridError::BadRun { row, run });
+                }
+
+                line.extend(std::iter::repeat_n(glyph, run_length));
+                run += 1;
+            }
+            lines.push(line);
+        }
+
+        Self::try_from_line_iter(lines.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tile_enum;
+
+    use super::*;
+
+    tile_enum! {
+        enum Warehouse {
+            Wall = '#',
+            Open = '.',
+            Box = 'O',
+            Robot = '@',
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let s = "########\n#..O.O.#\n##@.O..#\n########";
+        let grid: Grid<Warehouse> = s.parse().unwrap();
+        assert_eq!(
+            grid.get(&coord_2d::Coord2D::new(2, 2)),
+            Some(Warehouse::Robot)
+        );
+        assert_eq!(grid.to_string(), s);
+
+        let rle = grid.to_rle().unwrap();
+        assert_eq!(rle, "#8\n#1,.2,O1,.1,O1,.1,#1\n#2,@1,.1,O1,.2,#1\n#8");
+        assert_eq!(
+            Grid::<Warehouse>::from_rle(&rle).unwrap().to_string(),
+            grid.to_string()
+        );
+
+        let digits: Grid<usize> = "0123\n3333".parse().unwrap();
+        assert_eq!(digits.to_rle().unwrap(), "01,11,21,31\n34");
+        assert_eq!(
+            Grid::<usize>::from_rle(&digits.to_rle().unwrap())
+                .unwrap()
+                .to_string(),
+            "0123\n3333"
+        );
+
+        assert_eq!(
+            Grid::<usize>::from_rle("01,13\n3").unwrap_err(),
+            ParseGridError::BadRun { row: 1, run: 0 }
+        );
+    }
+
+    #[test]
+    fn test_undrawable_tiles() {
+        let multi_digit: Grid<usize> = Grid::new(vec![vec![1, 12], vec![3, 4]]);
+        assert_eq!(multi_digit.to_string(), "112\n34");
+        assert_eq!(multi_digit.try_render(), None);
+        assert_eq!(multi_digit.to_rle(), None);
+
+        let negative: Grid<i32> = Grid::new(vec![vec![-1, 2]]);
+        assert_eq!(negative.to_string(), "-12");
+        assert_eq!(negative.try_render(), None);
+        assert_eq!(negative.to_rle(), None);
+
+        let digits: Grid<usize> = Grid::new(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(digits.try_render(), Some("12\n34".to_string()));
+        let parsed: Grid<usize> = digits.try_render().unwrap().parse().unwrap();
+        assert_eq!(parsed.try_render(), digits.try_render());
+    }
+}
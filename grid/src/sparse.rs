@@ -0,0 +1,239 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use coord_2d::Coord2D;
+
+use crate::Grid;
+
+/// A grid that only stores the cells that have been set. Coordinates
+/// may be negative, and the grid grows in every direction as cells are set.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T: Copy + Display + PartialEq> {
+    inner: HashMap<Coord2D<isize>, T>,
+    /// The (top left, bottom right) corners of the occupied area (inclusive)
+    bounds: Option<(Coord2D<isize>, Coord2D<isize>)>,
+}
+
+impl<T: Copy + Display + PartialEq> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Display + PartialEq> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            inner: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Copy the cells of a dense grid; cells whose value is `empty` are left unset
+    pub fn from_grid(grid: &Grid<T>, empty: Option<T>) -> Self {
+        let mut result = Self::new();
+        for (coord, val) in grid.coords_and_vals::<isize>() {
+            if Some(val) != empty {
+                result.set(val, coord);
+            }
+        }
+        result
+    }
+
+    /// Build a dense grid covering the bounding box of this one, filling unset
+    /// cells with `empty`. The top left corner of the bounding box becomes (0, 0).
+    pub fn to_grid(&self, empty: T) -> Grid<T> {
+        let (min, max) = self.bounds.expect("cannot densify an empty grid");
+        let inner: Vec<Vec<T>> = (min.row..=max.row)
+            .map(|row| {
+                (min.col..=max.col)
+                    .map(|col| self.get(&Coord2D::new(row, col)).unwrap_or(empty))
+                    .collect()
+            })
+            .collect();
+
+        Grid::new(inner)
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn get(&self, coord: &Coord2D<isize>) -> Option<T> {
+        self.inner.get(coord).copied()
+    }
+
+    pub fn contains(&self, coord: &Coord2D<isize>) -> bool {
+        self.inner.contains_key(coord)
+    }
+
+    pub fn set(&mut self, val: T, coord: Coord2D<isize>) {
+        self.bounds = match self.bounds.take() {
+            None => Some((coord, coord)),
+            Some((min, max)) => Some((
+                Coord2D::new(min.row.min(coord.row), min.col.min(coord.col)),
+                Coord2D::new(max.row.max(coord.row), max.col.max(coord.col)),
+            )),
+        };
+        self.inner.insert(coord, val);
+    }
+
+    pub fn remove(&mut self, coord: &Coord2D<isize>) -> Option<T> {
+        let removed = self.inner.remove(coord);
+        if let Some((min, max)) = &self.bounds {
+            // the bounding box can only shrink if we removed a cell on its edge
+            if coord.row == min.row
+                || coord.row == max.row
+                || coord.col == min.col
+                || coord.col == max.col
+            {
+                self.recompute_bounds();
+            }
+        }
+        removed
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = if self.inner.is_empty() {
+            None
+        } else {
+            let keys = || self.inner.keys();
+            Some((
+                Coord2D::new(
+                    keys().map(|c| c.row).min().unwrap(),
+                    keys().map(|c| c.col).min().unwrap(),
+                ),
+                Coord2D::new(
+                    keys().map(|c| c.row).max().unwrap(),
+                    keys().map(|c| c.col).max().unwrap(),
+                ),
+            ))
+        }
+    }
+
+    /// The (top left, bottom right) corners of the smallest rectangle
+    /// containing every set cell (inclusive)
+    pub fn bounds(&self) -> Option<(Coord2D<isize>, Coord2D<isize>)> {
+        self.bounds
+    }
+
+    pub fn n_rows(&self) -> usize {
+        match &self.bounds {
+            Some((min, max)) => (max.row - min.row + 1) as usize,
+            None => 0,
+        }
+    }
+
+    pub fn n_cols(&self) -> usize {
+        match &self.bounds {
+            Some((min, max)) => (max.col - min.col + 1) as usize,
+            None => 0,
+        }
+    }
+
+    /// Find every coord whose value matches needle
+    pub fn find(&self, needle: T) -> HashSet<Coord2D<isize>> {
+        self.inner
+            .iter()
+            .filter(|(_, &val)| val == needle)
+            .map(|(coord, _)| *coord)
+            .collect()
+    }
+
+    /// Find the only coord whose value matches needle
+    pub fn find_one(&self, needle: T) -> Coord2D<isize> {
+        let all = self.find(needle);
+        if all.len() != 1 {
+            panic!("too many or too few to find just one");
+        }
+
+        all.into_iter().next().unwrap()
+    }
+
+    /// Every set cell, in row-major order
+    pub fn coords_and_vals(&self) -> impl Iterator<Item = (Coord2D<isize>, T)> + use<'_, T> {
+        let mut coords: Vec<&Coord2D<isize>> = self.inner.keys().collect();
+        coords.sort_by_key(|c| (c.row, c.col));
+        coords.into_iter().map(|coord| (*coord, self.inner[coord]))
+    }
+
+    /// Each row of the bounding box, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = Vec<Option<T>>> + use<'_, T> {
+        let origin = match &self.bounds {
+            Some((min, _)) => *min,
+            None => Coord2D::new(0, 0),
+        };
+        let n_cols = self.n_cols() as isize;
+
+        (0..self.n_rows() as isize).map(move |row_offset| {
+            (0..n_cols)
+                .map(|col_offset| {
+                    self.get(&Coord2D::new(
+                        origin.row + row_offset,
+                        origin.col + col_offset,
+                    ))
+                })
+                .collect()
+        })
+    }
+
+    /// Print the bounding box, drawing unset cells with `empty`
+    pub fn print(&self, empty: char) {
+        for row in self.rows() {
+            println!(
+                "{}",
+                row.into_iter()
+                    .map(|t| match t {
+                        Some(val) => val.to_string(),
+                        None => empty.to_string(),
+                    })
+                    .collect::<String>()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<char> = SparseGrid::new();
+        grid.set('a', Coord2D::new(-2, 3));
+        grid.set('b', Coord2D::new(1, -1));
+        grid.set('c', Coord2D::new(0, 0));
+        assert_eq!(
+            grid.bounds(),
+            Some((Coord2D::new(-2, -1), Coord2D::new(1, 3)))
+        );
+        assert_eq!((grid.n_rows(), grid.n_cols()), (4, 5));
+
+        assert_eq!(grid.remove(&Coord2D::new(-2, 3)), Some('a'));
+        assert_eq!(
+            grid.bounds(),
+            Some((Coord2D::new(0, -1), Coord2D::new(1, 0)))
+        );
+        assert_eq!(
+            grid.coords_and_vals().collect::<Vec<_>>(),
+            vec![(Coord2D::new(0, 0), 'c'), (Coord2D::new(1, -1), 'b')]
+        );
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense = Grid::from_line_iter([".#.", "..#", "###"].into_iter().map(|x| x.to_string()));
+        let sparse = SparseGrid::from_grid(&dense, Some('.'));
+        assert_eq!(sparse.len(), 5);
+        assert_eq!(sparse.find('#').len(), 5);
+
+        let round_trip = sparse.to_grid('.');
+        assert_eq!(
+            round_trip.rows().collect::<Vec<_>>(),
+            dense.rows().collect::<Vec<_>>()
+        );
+    }
+}
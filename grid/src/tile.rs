@@ -0,0 +1,192 @@
+use std::fmt::Display;
+
+use thiserror::Error;
+
+use crate::Grid;
+
+/// A value that is read from, and drawn as, a single character of a grid
+pub trait Tile: Copy + Display + PartialEq {
+    /// None if the character isn't a valid tile
+    fn from_char(c: char) -> Option<Self>;
+
+    /// None if the tile can't be drawn as a single character
+    fn to_char(&self) -> Option<char>;
+}
+
+impl Tile for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> Option<char> {
+        Some(*self)
+    }
+}
+
+/// Integers are read and drawn as a single decimal digit, so only
+/// the values 0 through 9 can be drawn; any other value has no char
+macro_rules! impl_digit_tile {
+    ($($t:ty),+) => {
+        $(
+            impl Tile for $t {
+                fn from_char(c: char) -> Option<Self> {
+                    c.to_digit(10).map(|d| d as $t)
+                }
+
+                fn to_char(&self) -> Option<char> {
+                    u32::try_from(*self)
+                        .ok()
+                        .and_then(|d| char::from_digit(d, 10))
+                }
+            }
+        )+
+    };
+}
+
+impl_digit_tile!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Define a fieldless enum of tiles, each with its glyph, along with
+/// its `Tile` and `Display` implementations:
+///
+/// ```
+/// grid::tile_enum! {
+///     pub enum Tile {
+///         Wall = '#',
+///         Open = '.',
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! tile_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident = $glyph:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($variant),+
+        }
+
+        impl $crate::Tile for $name {
+            fn from_char(c: char) -> Option<Self> {
+                match c {
+                    $($glyph => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn to_char(&self) -> Option<char> {
+                match self {
+                    $(Self::$variant => Some($glyph)),+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$variant => write!(f, "{}", $glyph)),+
+                }
+            }
+        }
+    };
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    #[error("unknown tile {c:?} at ({row}, {col})")]
+    UnknownTile { c: char, row: usize, col: usize },
+    #[error("row {row} has {found} columns; expected {expected}")]
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("a grid needs at least one row and one column")]
+    Empty,
+    #[error("run {run} of row {row} is malformed")]
+    BadRun { row: usize, run: usize },
+}
+
+impl<T: Tile> Grid<T> {
+    /// Like `Grid::from_line_iter`, but every character must be a valid tile
+    /// and every row must have the same length
+    pub fn try_from_line_iter(
+        input: impl Iterator<Item = impl AsRef<str>>,
+    ) -> Result<Self, ParseGridError> {
+        let mut inner: Vec<Vec<T>> = Vec::new();
+        for (row, line) in input.enumerate() {
+            let tiles = line
+                .as_ref()
+                .chars()
+                .enumerate()
+                .map(|(col, c)| T::from_char(c).ok_or(ParseGridError::UnknownTile { c, row, col }))
+                .collect::<Result<Vec<T>, ParseGridError>>()?;
+
+            if let Some(first) = inner.first() {
+                if first.len() != tiles.len() {
+                    return Err(ParseGridError::RaggedRow {
+                        row,
+                        expected: first.len(),
+                        found: tiles.len(),
+                    });
+                }
+            }
+            inner.push(tiles);
+        }
+
+        match inner.first() {
+            Some(first) if !first.is_empty() => Ok(Self::new(inner)),
+            _ => Err(ParseGridError::Empty),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    tile_enum! {
+        enum Maze {
+            Wall = '#',
+            Open = '.',
+            Start = 'S',
+        }
+    }
+
+    #[test]
+    fn test_try_from_line_iter() {
+        let grid: Grid<Maze> = Grid::try_from_line_iter(["#S.", "#.#"].into_iter()).unwrap();
+        assert_eq!(grid.row(0), vec![Maze::Wall, Maze::Start, Maze::Open]);
+        assert_eq!(
+            grid.rows()
+                .map(|row| row.iter().map(|t| t.to_string()).collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["#S.", "#.#"]
+        );
+
+        assert_eq!(
+            Grid::<Maze>::try_from_line_iter(["#S.", "#2#"].into_iter()).unwrap_err(),
+            ParseGridError::UnknownTile {
+                c: '2',
+                row: 1,
+                col: 1
+            }
+        );
+        assert_eq!(
+            Grid::<Maze>::try_from_line_iter(["#S.", "#."].into_iter()).unwrap_err(),
+            ParseGridError::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(
+            Grid::<Maze>::try_from_line_iter(std::iter::empty::<&str>()).unwrap_err(),
+            ParseGridError::Empty
+        );
+    }
+}
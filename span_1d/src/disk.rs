@@ -0,0 +1,422 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::{IntervalSet, Span1D};
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseDiskMapError {
+    #[error("invalid digit {c:?} at position {position}")]
+    InvalidDigit { c: char, position: usize },
+}
+
+/// A disk of blocks, each either free or holding part of a file (by id)
+///
+/// ```
+/// use span_1d::{BlockLevel, DiskMap};
+///
+/// let mut disk: DiskMap = "12345".parse().unwrap();
+/// assert_eq!(disk.to_string(), "0..111....22222");
+/// disk.compact(&BlockLevel);
+/// assert_eq!(disk.to_string(), "022111222......");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMap {
+    blocks: Vec<Option<usize>>,
+}
+
+/// Slide the blocks in from so that they start at to, which must not be
+/// after from.start: block from.start + i is swapped with block to + i,
+/// in increasing order of i, so whatever was in the way ends up to the right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockMove {
+    pub from: Span1D<usize>,
+    pub to: usize,
+}
+
+impl BlockMove {
+    pub fn new(from: Span1D<usize>, to: usize) -> Self {
+        Self { from, to }
+    }
+}
+
+/// How scattered the files and free space on a disk are
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Fragmentation {
+    pub free_blocks: usize,
+    /// the number of separate runs of free blocks
+    pub free_spans: usize,
+    pub largest_free_span: usize,
+    /// the number of files stored in more than one run
+    pub fragmented_files: usize,
+}
+
+impl Fragmentation {
+    /// The share of free blocks outside the largest free span; 0 when the
+    /// free space is in one piece
+    pub fn external(&self) -> f64 {
+        if self.free_blocks == 0 {
+            return 0.0;
+        }
+        1.0 - self.largest_free_span as f64 / self.free_blocks as f64
+    }
+}
+
+impl DiskMap {
+    pub fn new(blocks: Vec<Option<usize>>) -> Self {
+        Self { blocks }
+    }
+
+    pub fn blocks(&self) -> &[Option<usize>] {
+        &self.blocks
+    }
+
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Every maximal run of blocks from a single file, with the file's id,
+    /// in order
+    pub fn file_runs(&self) -> Vec<(usize, Span1D<usize>)> {
+        let mut result: Vec<(usize, Span1D<usize>)> = Vec::new();
+        for (idx, block) in self.blocks.iter().enumerate() {
+            let Some(id) = block else {
+                continue;
+            };
+            match result.last_mut() {
+                Some((last_id, run)) if last_id == id && run.end() == idx => run.increment(),
+                _ => result.push((*id, Span1D::new(idx, 1))),
+            }
+        }
+        result
+    }
+
+    pub fn free_spans(&self) -> IntervalSet<usize> {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| block.is_none())
+            .map(|(idx, _)| Span1D::new(idx, 1))
+            .collect()
+    }
+
+    /// The sum of each block's position times its file id
+    pub fn check_sum(&self) -> usize {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, block)| block.map(|id| id * idx))
+            .sum()
+    }
+
+    pub fn fragmentation(&self) -> Fragmentation {
+        let free_spans = self.free_spans();
+        let mut run_cts: HashMap<usize, usize> = HashMap::new();
+        for (id, _) in self.file_runs() {
+            *run_cts.entry(id).or_insert(0) += 1;
+        }
+
+        Fragmentation {
+            free_blocks: free_spans.iter().map(|span| span.len).sum(),
+            free_spans: free_spans.len(),
+            largest_free_span: free_spans.iter().map(|span| span.len).max().unwrap_or(0),
+            fragmented_files: run_cts.values().filter(|ct| **ct > 1).count(),
+        }
+    }
+
+    pub fn apply(&mut self, block_move: &BlockMove) {
+        for offset in 0..block_move.from.len {
+            self.blocks
+                .swap(block_move.from.start + offset, block_move.to + offset);
+        }
+    }
+
+    pub fn compact(&mut self, strategy: &impl CompactionStrategy) {
+        for block_move in strategy.plan(self) {
+            self.apply(&block_move);
+        }
+    }
+
+    /// Each move strategy makes, with the disk as it is right after that move
+    pub fn snapshots(
+        &self,
+        strategy: &impl CompactionStrategy,
+    ) -> impl Iterator<Item = (BlockMove, DiskMap)> {
+        let mut disk = self.clone();
+        strategy.plan(self).into_iter().map(move |block_move| {
+            disk.apply(&block_move);
+            (block_move, disk.clone())
+        })
+    }
+}
+
+impl FromStr for DiskMap {
+    type Err = ParseDiskMapError;
+
+    /// The dense format: digits alternate between the length of a file and
+    /// the length of the free space after it, and file ids count up from 0
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut blocks: Vec<Option<usize>> = Vec::new();
+        let leading_whitespace = s.len() - s.trim_start().len();
+        for (digit_idx, (offset, c)) in s.trim().char_indices().enumerate() {
+            let len = c.to_digit(10).ok_or(ParseDiskMapError::InvalidDigit {
+                c,
+                position: leading_whitespace + offset,
+            })?;
+            let block = (digit_idx % 2 == 0).then_some(digit_idx / 2);
+            blocks.extend(std::iter::repeat_n(block, len as usize));
+        }
+        Ok(Self::new(blocks))
+    }
+}
+
+impl fmt::Display for DiskMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for block in self.blocks.iter() {
+            match block {
+                Some(id) => write!(f, "{}", id)?,
+                None => write!(f, ".")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A way of moving files toward the start of a disk
+pub trait CompactionStrategy {
+    /// The moves that compact disk, in the order to apply them
+    fn plan(&self, disk: &DiskMap) -> Vec<BlockMove>;
+}
+
+/// Move the last used block into the first free block, one block at a time,
+/// until no free block comes before a used one
+pub struct BlockLevel;
+
+impl CompactionStrategy for BlockLevel {
+    fn plan(&self, disk: &DiskMap) -> Vec<BlockMove> {
+        let blocks = disk.blocks();
+        let mut moves: Vec<BlockMove> = Vec::new();
+        let mut free_idx: usize = 0;
+        let mut used_end: usize = blocks.len();
+        loop {
+            while free_idx < blocks.len() && blocks[free_idx].is_some() {
+                free_idx += 1;
+            }
+            while used_end > 0 && blocks[used_end - 1].is_none() {
+                used_end -= 1;
+            }
+            if free_idx + 1 >= used_end {
+                return moves;
+            }
+            moves.push(BlockMove::new(Span1D::new(used_end - 1, 1), free_idx));
+            free_idx += 1;
+            used_end -= 1;
+        }
+    }
+}
+
+/// Visit each run of file blocks once, right to left, moving the whole run
+/// into the leftmost free span (before it) with room for it
+pub struct FirstFit;
+
+impl CompactionStrategy for FirstFit {
+    fn plan(&self, disk: &DiskMap) -> Vec<BlockMove> {
+        plan_whole_runs(disk, IntervalSet::allocate_first_fit)
+    }
+}
+
+/// Like `FirstFit`, but use the smallest free span with room for the run
+pub struct BestFit;
+
+impl CompactionStrategy for BestFit {
+    fn plan(&self, disk: &DiskMap) -> Vec<BlockMove> {
+        plan_whole_runs(disk, IntervalSet::allocate_best_fit)
+    }
+}
+
+fn plan_whole_runs(
+    disk: &DiskMap,
+    allocate: impl Fn(&mut IntervalSet<usize>, usize, usize) -> Option<Span1D<usize>>,
+) -> Vec<BlockMove> {
+    // runs only ever move left, and we visit them right to left, so the
+    // space a run leaves behind is never a candidate for a later run
+    let mut free_spans = disk.free_spans();
+    disk.file_runs()
+        .into_iter()
+        .rev()
+        .filter_map(|(_, run)| {
+            allocate(&mut free_spans, run.len, run.start)
+                .map(|dest| BlockMove::new(run, dest.start))
+        })
+        .collect()
+}
+
+/// Gather every file into a single run, packed from the start of the disk
+/// with no gaps, keeping the files in the order they first appear
+pub struct Defragment;
+
+impl CompactionStrategy for Defragment {
+    fn plan(&self, disk: &DiskMap) -> Vec<BlockMove> {
+        let mut blocks = disk.blocks().to_vec();
+        let mut file_order: Vec<usize> = Vec::new();
+        let mut positions: HashMap<usize, BTreeSet<usize>> = HashMap::new();
+        for (idx, block) in blocks.iter().enumerate() {
+            if let Some(id) = block {
+                positions
+                    .entry(*id)
+                    .or_insert_with(|| {
+                        file_order.push(*id);
+                        BTreeSet::new()
+                    })
+                    .insert(idx);
+            }
+        }
+
+        let mut moves: Vec<BlockMove> = Vec::new();
+        let mut write_head: usize = 0;
+        for id in file_order {
+            // everything before the write head is already in place, so
+            // each block of this file is at or after it
+            while let Some(idx) = positions.get_mut(&id).unwrap().pop_first() {
+                if idx != write_head {
+                    if let Some(other_id) = blocks[write_head] {
+                        let other_positions = positions.get_mut(&other_id).unwrap();
+                        other_positions.remove(&write_head);
+                        other_positions.insert(idx);
+                    }
+                    blocks.swap(idx, write_head);
+
+                    // extend the last move when this swap is its next step
+                    match moves.last_mut() {
+                        Some(last)
+                            if last.from.end() == idx && last.to + last.from.len == write_head =>
+                        {
+                            last.from.increment()
+                        }
+                        _ => moves.push(BlockMove::new(Span1D::new(idx, 1), write_head)),
+                    }
+                }
+                write_head += 1;
+            }
+        }
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2333133121414131402";
+
+    fn compacted(disk: &DiskMap, strategy: &impl CompactionStrategy) -> DiskMap {
+        let mut disk = disk.clone();
+        disk.compact(strategy);
+        disk
+    }
+
+    #[test]
+    fn test_parse() {
+        let disk: DiskMap = "12345\n".parse().unwrap();
+        assert_eq!(disk.to_string(), "0..111....22222");
+        assert_eq!(
+            disk.file_runs(),
+            vec![
+                (0, Span1D::new(0, 1)),
+                (1, Span1D::new(3, 3)),
+                (2, Span1D::new(10, 5))
+            ]
+        );
+        assert_eq!(
+            "12a".parse::<DiskMap>(),
+            Err(ParseDiskMapError::InvalidDigit {
+                c: 'a',
+                position: 2
+            })
+        );
+        // positions are byte offsets into the original string, and the
+        // leading no-break space is two bytes long
+        assert_eq!(
+            "\u{a0}1x".parse::<DiskMap>(),
+            Err(ParseDiskMapError::InvalidDigit {
+                c: 'x',
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_strategies() {
+        let disk: DiskMap = EXAMPLE.parse().unwrap();
+
+        let block_level = compacted(&disk, &BlockLevel);
+        assert_eq!(
+            block_level.to_string(),
+            "0099811188827773336446555566.............."
+        );
+        assert_eq!(block_level.check_sum(), 1928);
+
+        let first_fit = compacted(&disk, &FirstFit);
+        assert_eq!(
+            first_fit.to_string(),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+        assert_eq!(first_fit.check_sum(), 2858);
+
+        let disk = DiskMap::new(vec![
+            Some(0),
+            None,
+            None,
+            None,
+            Some(1),
+            None,
+            None,
+            Some(2),
+            Some(2),
+        ]);
+        assert_eq!(compacted(&disk, &FirstFit).to_string(), "0221.....");
+        assert_eq!(compacted(&disk, &BestFit).to_string(), "01...22..");
+    }
+
+    #[test]
+    fn test_defragment() {
+        let disk: DiskMap = EXAMPLE.parse().unwrap();
+        let block_level = compacted(&disk, &BlockLevel);
+        assert_eq!(block_level.fragmentation().fragmented_files, 2);
+
+        let defragmented = compacted(&block_level, &Defragment);
+        assert_eq!(
+            defragmented.to_string(),
+            "0099888811127773336666445555..............",
+        );
+        assert_eq!(
+            defragmented.fragmentation(),
+            Fragmentation {
+                free_blocks: 14,
+                free_spans: 1,
+                largest_free_span: 14,
+                fragmented_files: 0
+            }
+        );
+
+        let fragmented = DiskMap::new(vec![Some(1), None, Some(0), Some(1), None, Some(0)]);
+        assert_eq!(fragmented.fragmentation().external(), 0.5);
+        assert_eq!(compacted(&fragmented, &Defragment).to_string(), "1100..");
+    }
+
+    #[test]
+    fn test_snapshots() {
+        let disk: DiskMap = "12345".parse().unwrap();
+        let snapshots: Vec<(BlockMove, DiskMap)> = disk.snapshots(&BlockLevel).collect();
+        assert_eq!(snapshots.len(), 5);
+        assert_eq!(snapshots[0].0, BlockMove::new(Span1D::new(14, 1), 1));
+        assert_eq!(snapshots[0].1.to_string(), "02.111....2222.");
+        assert_eq!(snapshots[4].1, compacted(&disk, &BlockLevel));
+    }
+}
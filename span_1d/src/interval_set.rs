@@ -0,0 +1,228 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use num::Integer;
+
+use crate::Span1D;
+
+/// A set of values stored as disjoint spans, where overlapping or adjacent
+/// spans are always merged. Inserting and removing a span take O(log n) time
+/// (plus the number of spans it touches); first-fit queries take
+/// O(k log n), where k is the number of distinct span lengths that fit.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet<T: Integer + Copy> {
+    /// start -> len
+    by_start: BTreeMap<T, T>,
+    /// len -> the starts of every span with that len
+    by_len: BTreeMap<T, BTreeSet<T>>,
+}
+
+impl<T: Integer + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            by_start: BTreeMap::new(),
+            by_len: BTreeMap::new(),
+        }
+    }
+
+    /// The number of (merged) spans
+    pub fn len(&self) -> usize {
+        self.by_start.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_start.is_empty()
+    }
+
+    /// The spans in order
+    pub fn iter(&self) -> impl Iterator<Item = Span1D<T>> + use<'_, T> {
+        self.by_start
+            .iter()
+            .map(|(start, len)| Span1D::new(*start, *len))
+    }
+
+    /// The span containing x, if any
+    pub fn span_containing(&self, x: T) -> Option<Span1D<T>> {
+        self.by_start
+            .range(..=x)
+            .next_back()
+            .map(|(start, len)| Span1D::new(*start, *len))
+            .filter(|span| span.contains(x))
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.span_containing(x).is_some()
+    }
+
+    fn add_span(&mut self, span: Span1D<T>) {
+        self.by_start.insert(span.start, span.len);
+        self.by_len.entry(span.len).or_default().insert(span.start);
+    }
+
+    fn remove_span(&mut self, span: Span1D<T>) {
+        self.by_start.remove(&span.start);
+        if let Some(starts) = self.by_len.get_mut(&span.len) {
+            starts.remove(&span.start);
+            if starts.is_empty() {
+                self.by_len.remove(&span.len);
+            }
+        }
+    }
+
+    /// The stored spans that overlap span or (if adjacent) touch it
+    fn touching(&self, span: &Span1D<T>) -> Vec<Span1D<T>> {
+        let previous = self
+            .by_start
+            .range(..span.start)
+            .next_back()
+            .map(|(start, len)| Span1D::new(*start, *len))
+            .filter(|previous| previous.end() >= span.start);
+        previous
+            .into_iter()
+            .chain(
+                self.by_start
+                    .range(span.start..=span.end())
+                    .map(|(start, len)| Span1D::new(*start, *len)),
+            )
+            .collect()
+    }
+
+    pub fn insert(&mut self, span: Span1D<T>) {
+        if span.is_empty() {
+            return;
+        }
+        let mut merged = span;
+        for existing in self.touching(&span) {
+            self.remove_span(existing);
+            merged = merged.union(&existing).unwrap();
+        }
+        self.add_span(merged);
+    }
+
+    pub fn remove(&mut self, span: Span1D<T>) {
+        if span.is_empty() {
+            return;
+        }
+        for existing in self.touching(&span) {
+            if existing.overlaps(&span) {
+                self.remove_span(existing);
+                for remainder in existing.subtract(&span) {
+                    self.add_span(remainder);
+                }
+            }
+        }
+    }
+
+    /// The lowest span of exactly len values that is in the set and ends
+    /// at or before limit
+    pub fn first_fit(&self, len: T, limit: T) -> Option<Span1D<T>> {
+        self.by_len
+            .range(len..)
+            .filter_map(|(_, starts)| starts.first())
+            .min()
+            .map(|start| Span1D::new(*start, len))
+            .filter(|fit| fit.end() <= limit)
+    }
+
+    /// Remove and return `IntervalSet::first_fit`
+    pub fn allocate_first_fit(&mut self, len: T, limit: T) -> Option<Span1D<T>> {
+        let fit = self.first_fit(len, limit)?;
+        self.remove(fit);
+        Some(fit)
+    }
+
+    /// The len values at the start of the smallest span (the lowest, on ties)
+    /// that has room for them and where they end at or before limit
+    pub fn best_fit(&self, len: T, limit: T) -> Option<Span1D<T>> {
+        self.by_len
+            .range(len..)
+            .filter_map(|(_, starts)| starts.first())
+            .map(|start| Span1D::new(*start, len))
+            .find(|fit| fit.end() <= limit)
+    }
+
+    /// Remove and return `IntervalSet::best_fit`
+    pub fn allocate_best_fit(&mut self, len: T, limit: T) -> Option<Span1D<T>> {
+        let fit = self.best_fit(len, limit)?;
+        self.remove(fit);
+        Some(fit)
+    }
+}
+
+impl<T: Integer + Copy> FromIterator<Span1D<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Span1D<T>>>(iter: I) -> Self {
+        let mut result = Self::new();
+        for span in iter {
+            result.insert(span);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set: IntervalSet<i32> =
+            [Span1D::new(0, 2), Span1D::new(5, 2)].into_iter().collect();
+        set.insert(Span1D::new(2, 1));
+        set.insert(Span1D::new(10, 0));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Span1D::new(0, 3), Span1D::new(5, 2)]
+        );
+
+        // bridges the gap between the two spans
+        set.insert(Span1D::new(3, 2));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![Span1D::new(0, 7)]);
+
+        set.remove(Span1D::new(2, 3));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Span1D::new(0, 2), Span1D::new(5, 2)]
+        );
+        assert!(set.contains(6));
+        assert!(!set.contains(4));
+        assert_eq!(set.span_containing(1), Some(Span1D::new(0, 2)));
+
+        set.remove(Span1D::new(-5, 20));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_first_fit() {
+        let mut set: IntervalSet<usize> = [Span1D::new(2, 1), Span1D::new(5, 3), Span1D::new(9, 4)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.first_fit(1, 100), Some(Span1D::new(2, 1)));
+        assert_eq!(set.first_fit(2, 100), Some(Span1D::new(5, 2)));
+        assert_eq!(set.first_fit(4, 12), None);
+        assert_eq!(set.first_fit(5, 100), None);
+
+        assert_eq!(set.allocate_first_fit(2, 7), Some(Span1D::new(5, 2)));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Span1D::new(2, 1), Span1D::new(7, 1), Span1D::new(9, 4)]
+        );
+        assert_eq!(set.allocate_first_fit(2, 100), Some(Span1D::new(9, 2)));
+        assert_eq!(set.first_fit(1, 100), Some(Span1D::new(2, 1)));
+    }
+
+    #[test]
+    fn test_best_fit() {
+        let mut set: IntervalSet<usize> =
+            [Span1D::new(0, 5), Span1D::new(7, 2), Span1D::new(11, 2)]
+                .into_iter()
+                .collect();
+        assert_eq!(set.best_fit(2, 100), Some(Span1D::new(7, 2)));
+        assert_eq!(set.best_fit(3, 100), Some(Span1D::new(0, 3)));
+        // both spans of 2 end too late, so fall back to a bigger one
+        assert_eq!(set.best_fit(2, 8), Some(Span1D::new(0, 2)));
+        assert_eq!(set.best_fit(2, 1), None);
+
+        assert_eq!(set.allocate_best_fit(1, 100), Some(Span1D::new(7, 1)));
+        assert_eq!(set.allocate_best_fit(1, 100), Some(Span1D::new(8, 1)));
+        assert_eq!(set.best_fit(1, 100), Some(Span1D::new(11, 1)));
+    }
+}
@@ -0,0 +1,162 @@
+use num::Integer;
+
+use crate::Span1D;
+
+/// A static collection of (possibly overlapping) spans, each with a value,
+/// that answers "which spans overlap this one?" in O(log n + k) time, where
+/// k is the number of matches. Build it in bulk with `IntervalTree::new` or
+/// `collect`.
+///
+/// The spans are kept sorted by start and treated as an implicit balanced
+/// binary tree, where each node also records the largest end in its subtree.
+#[derive(Debug, Clone)]
+pub struct IntervalTree<T: Integer + Copy, V> {
+    entries: Vec<(Span1D<T>, V)>,
+    /// the largest end of any span in the subtree rooted at each index
+    max_end: Vec<T>,
+}
+
+impl<T: Integer + Copy, V> IntervalTree<T, V> {
+    pub fn new(entries: impl IntoIterator<Item = (Span1D<T>, V)>) -> Self {
+        // empty spans never overlap anything
+        let mut entries: Vec<(Span1D<T>, V)> = entries
+            .into_iter()
+            .filter(|(span, _)| !span.is_empty())
+            .collect();
+        entries.sort_by_key(|(span, _)| (span.start, span.end()));
+
+        let mut max_end: Vec<T> = entries.iter().map(|(span, _)| span.end()).collect();
+        Self::build(&entries, &mut max_end, 0, entries.len());
+        Self { entries, max_end }
+    }
+
+    /// Fill in max_end for the subtree over lo..hi, returning its largest end
+    fn build(entries: &[(Span1D<T>, V)], max_end: &mut [T], lo: usize, hi: usize) -> Option<T> {
+        if lo >= hi {
+            return None;
+        }
+        let mid = (lo + hi) / 2;
+        let mut result = entries[mid].0.end();
+        for child in [
+            Self::build(entries, max_end, lo, mid),
+            Self::build(entries, max_end, mid + 1, hi),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            result = std::cmp::max(result, child);
+        }
+        max_end[mid] = result;
+        Some(result)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every span (and its value) sharing at least one value with span,
+    /// ordered by start
+    pub fn overlapping(&self, span: &Span1D<T>) -> Vec<(&Span1D<T>, &V)> {
+        let mut result: Vec<(&Span1D<T>, &V)> = Vec::new();
+        if !span.is_empty() {
+            self.collect_overlapping(span, 0, self.entries.len(), &mut result);
+        }
+        result
+    }
+
+    fn collect_overlapping<'a>(
+        &'a self,
+        span: &Span1D<T>,
+        lo: usize,
+        hi: usize,
+        result: &mut Vec<(&'a Span1D<T>, &'a V)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        // nothing in this subtree reaches the query
+        if self.max_end[mid] <= span.start {
+            return;
+        }
+
+        self.collect_overlapping(span, lo, mid, result);
+        let (candidate, value) = &self.entries[mid];
+        // everything from here on starts too late
+        if candidate.start >= span.end() {
+            return;
+        }
+        if candidate.overlaps(span) {
+            result.push((candidate, value));
+        }
+        self.collect_overlapping(span, mid + 1, hi, result);
+    }
+
+    /// Every span (and its value) that contains x
+    pub fn stab(&self, x: T) -> Vec<(&Span1D<T>, &V)> {
+        self.overlapping(&Span1D::new(x, T::one()))
+    }
+}
+
+impl<T: Integer + Copy, V> FromIterator<(Span1D<T>, V)> for IntervalTree<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Span1D<T>, V)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<T: Integer + Copy> FromIterator<Span1D<T>> for IntervalTree<T, ()> {
+    fn from_iter<I: IntoIterator<Item = Span1D<T>>>(iter: I) -> Self {
+        Self::new(iter.into_iter().map(|span| (span, ())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping() {
+        let spans: Vec<Span1D<i64>> = (0..50)
+            .map(|i| Span1D::new((i * 37) % 101 - 20, (i * 13) % 17))
+            .collect();
+        let tree: IntervalTree<i64, usize> = spans.iter().copied().zip(0..).collect();
+
+        for start in -30..100 {
+            for len in [1, 3, 20] {
+                let query = Span1D::new(start, len);
+                let mut expected: Vec<usize> = (0..spans.len())
+                    .filter(|idx| spans[*idx].overlaps(&query))
+                    .collect();
+                let mut found: Vec<usize> = tree
+                    .overlapping(&query)
+                    .into_iter()
+                    .map(|(_, idx)| *idx)
+                    .collect();
+                expected.sort();
+                found.sort();
+                assert_eq!(found, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stab() {
+        let tree: IntervalTree<usize, ()> =
+            [Span1D::new(0, 5), Span1D::new(3, 2), Span1D::new(6, 1)]
+                .into_iter()
+                .collect();
+        assert_eq!(
+            tree.stab(4)
+                .into_iter()
+                .map(|(span, _)| *span)
+                .collect::<Vec<_>>(),
+            vec![Span1D::new(0, 5), Span1D::new(3, 2)]
+        );
+        assert!(tree.stab(5).is_empty());
+        assert_eq!(tree.stab(6).len(), 1);
+    }
+}
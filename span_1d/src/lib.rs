@@ -1,30 +1,176 @@
 use num::Integer;
 
-#[derive(Debug, Clone)]
-pub struct Span1D<T: Integer + Copy + TryFrom<usize>>
-where
-    <T as TryFrom<usize>>::Error: std::fmt::Debug,
-{
+mod disk;
+mod interval_set;
+mod interval_tree;
+mod rect;
+mod segment_tree;
+
+pub use disk::{
+    BestFit, BlockLevel, BlockMove, CompactionStrategy, Defragment, DiskMap, FirstFit,
+    Fragmentation, ParseDiskMapError,
+};
+pub use interval_set::IntervalSet;
+pub use interval_tree::IntervalTree;
+pub use rect::{Rect, RectSet};
+pub use segment_tree::{RangeAddMax, RangeAddMin, RangeAddSum, SegmentOp, SegmentTree};
+
+/// The half-open range of values [start, start + len)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span1D<T: Integer + Copy> {
     pub start: T,
-    pub len: usize,
+    pub len: T,
 }
 
-impl<T: Integer + Copy + TryFrom<usize>> Span1D<T>
-where
-    <T as TryFrom<usize>>::Error: std::fmt::Debug,
-{
-    pub fn new(start: T, len: usize) -> Self
-    where
-        <T as TryFrom<usize>>::Error: std::fmt::Debug,
-    {
-        Self { start, len }
+impl<T: Integer + Copy> Span1D<T> {
+    /// A negative len is clamped to 0 (an empty span at start)
+    pub fn new(start: T, len: T) -> Self {
+        Self {
+            start,
+            len: std::cmp::max(len, T::zero()),
+        }
+    }
+
+    /// The span [start, end); empty if end <= start
+    pub fn from_bounds(start: T, end: T) -> Self {
+        if end > start {
+            Self::new(start, end - start)
+        } else {
+            Self::new(start, T::zero())
+        }
     }
 
     pub fn increment(&mut self) {
-        self.len += 1
+        self.len = self.len + T::one()
     }
 
+    /// The first value after the span
     pub fn end(&self) -> T {
-        self.start + T::try_from(self.len).unwrap()
+        self.start + self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len <= T::zero()
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end()
+    }
+
+    /// Whether the spans share at least one value
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The values in both spans; None if there aren't any
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let result = Self::from_bounds(
+            std::cmp::max(self.start, other.start),
+            std::cmp::min(self.end(), other.end()),
+        );
+        (!result.is_empty()).then_some(result)
+    }
+
+    /// The values in either span, if that's a single span
+    /// (the spans overlap or are adjacent); None otherwise
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() {
+            return Some(*other);
+        }
+        if other.is_empty() {
+            return Some(*self);
+        }
+        if self.start > other.end() || other.start > self.end() {
+            return None;
+        }
+        Some(Self::from_bounds(
+            std::cmp::min(self.start, other.start),
+            std::cmp::max(self.end(), other.end()),
+        ))
+    }
+
+    /// The values before at and the values from at onward;
+    /// either side is None if it would be empty
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let at = num::clamp(at, self.start, self.end());
+        let before = Self::from_bounds(self.start, at);
+        let after = Self::from_bounds(at, self.end());
+        (
+            (!before.is_empty()).then_some(before),
+            (!after.is_empty()).then_some(after),
+        )
+    }
+
+    /// The same span moved by offset
+    pub fn shift(&self, offset: T) -> Self {
+        Self::new(self.start + offset, self.len)
+    }
+
+    /// The values in this span but not in other, as up to two spans
+    /// (before and after other), in order
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        [
+            Self::from_bounds(self.start, other.start),
+            Self::from_bounds(other.end(), self.end()),
+        ]
+        .into_iter()
+        .filter(|span| !span.is_empty())
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let a: Span1D<i32> = Span1D::new(0, 5);
+        let b: Span1D<i32> = Span1D::new(3, 4);
+        let c: Span1D<i32> = Span1D::new(5, 2);
+
+        assert!(a.contains(4));
+        assert!(!a.contains(5));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&c));
+
+        assert_eq!(a.intersection(&b), Some(Span1D::new(3, 2)));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.union(&b), Some(Span1D::new(0, 7)));
+        assert_eq!(a.union(&c), Some(Span1D::new(0, 7)));
+        assert_eq!(a.union(&c.shift(1)), None);
+
+        assert_eq!(a.subtract(&b), vec![Span1D::new(0, 3)]);
+        assert_eq!(
+            a.subtract(&Span1D::new(1, 2)),
+            vec![Span1D::new(0, 1), Span1D::new(3, 2)]
+        );
+        assert_eq!(a.subtract(&c), vec![a]);
+        assert_eq!(a.subtract(&Span1D::new(-1, 10)), vec![]);
+    }
+
+    #[test]
+    fn test_negative_len() {
+        let span: Span1D<i32> = Span1D::new(0, -3);
+        assert!(span.is_empty());
+        assert_eq!(span.end(), 0);
+        assert!(!span.contains(-1));
+        assert!(Span1D { start: 5, len: -2 }.is_empty());
+    }
+
+    #[test]
+    fn test_split_and_shift() {
+        let a: Span1D<usize> = Span1D::new(2, 4);
+        assert_eq!(
+            a.split_at(3),
+            (Some(Span1D::new(2, 1)), Some(Span1D::new(3, 3)))
+        );
+        assert_eq!(a.split_at(2), (None, Some(a)));
+        assert_eq!(a.split_at(10), (Some(a), None));
+        assert_eq!(a.shift(3), Span1D::new(5, 4));
+        assert_eq!(a.end(), 6);
     }
 }
@@ -0,0 +1,228 @@
+use std::hash::Hash;
+
+use coord_2d::Coord2D;
+use num::Integer;
+
+use crate::{IntervalSet, Span1D};
+
+/// Every coord whose row is in rows and whose column is in cols
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T: Integer + Copy + Hash> {
+    pub rows: Span1D<T>,
+    pub cols: Span1D<T>,
+}
+
+impl<T: Integer + Copy + Hash> Rect<T> {
+    pub fn new(rows: Span1D<T>, cols: Span1D<T>) -> Self {
+        Self { rows, cols }
+    }
+
+    /// The rect from top_left to bottom_right, inclusive
+    pub fn from_corners(top_left: &Coord2D<T>, bottom_right: &Coord2D<T>) -> Self {
+        Self::new(
+            Span1D::from_bounds(top_left.row, bottom_right.row + T::one()),
+            Span1D::from_bounds(top_left.col, bottom_right.col + T::one()),
+        )
+    }
+
+    pub fn top_left(&self) -> Coord2D<T> {
+        Coord2D::new(self.rows.start, self.cols.start)
+    }
+
+    /// The last coord in the rect (inclusive); None if it's empty
+    pub fn bottom_right(&self) -> Option<Coord2D<T>> {
+        (!self.is_empty())
+            .then(|| Coord2D::new(self.rows.end() - T::one(), self.cols.end() - T::one()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty() || self.cols.is_empty()
+    }
+
+    pub fn area(&self) -> T {
+        self.rows.len * self.cols.len
+    }
+
+    pub fn contains(&self, coord: &Coord2D<T>) -> bool {
+        self.rows.contains(coord.row) && self.cols.contains(coord.col)
+    }
+
+    /// The coords in both rects; None if there aren't any
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.rows.intersection(&other.rows)?,
+            self.cols.intersection(&other.cols)?,
+        ))
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The north west, north east, south west and south east quarters.
+    /// When a side has odd length, its middle row or column belongs to
+    /// no quadrant.
+    pub fn quadrants(&self) -> [Self; 4] {
+        let two = T::one() + T::one();
+        let halve = |span: &Span1D<T>| {
+            let half = span.len / two;
+            (
+                Span1D::new(span.start, half),
+                Span1D::from_bounds(span.end() - half, span.end()),
+            )
+        };
+        let (north, south) = halve(&self.rows);
+        let (west, east) = halve(&self.cols);
+        [
+            Self::new(north, west),
+            Self::new(north, east),
+            Self::new(south, west),
+            Self::new(south, east),
+        ]
+    }
+
+    /// Every coord in the rect, in row-major order
+    pub fn coords(&self) -> impl Iterator<Item = Coord2D<T>> + use<T> {
+        let cols = self.cols;
+        span_values(self.rows)
+            .flat_map(move |row| span_values(cols).map(move |col| Coord2D::new(row, col)))
+    }
+}
+
+fn span_values<T: Integer + Copy>(span: Span1D<T>) -> impl Iterator<Item = T> {
+    std::iter::successors(Some(span.start), |x| Some(*x + T::one()))
+        .take_while(move |x| *x < span.end())
+}
+
+/// A collection of (possibly overlapping) rects
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RectSet<T: Integer + Copy + Hash> {
+    rects: Vec<Rect<T>>,
+}
+
+impl<T: Integer + Copy + Hash> RectSet<T> {
+    pub fn new() -> Self {
+        Self { rects: Vec::new() }
+    }
+
+    pub fn insert(&mut self, rect: Rect<T>) {
+        if !rect.is_empty() {
+            self.rects.push(rect);
+        }
+    }
+
+    pub fn contains(&self, coord: &Coord2D<T>) -> bool {
+        self.rects.iter().any(|rect| rect.contains(coord))
+    }
+
+    /// The number of coords in at least one rect. Sweeps across the
+    /// columns where a rect starts or ends; between two such columns,
+    /// the covered rows don't change.
+    pub fn union_area(&self) -> T {
+        let mut boundaries: Vec<T> = self
+            .rects
+            .iter()
+            .flat_map(|rect| [rect.cols.start, rect.cols.end()])
+            .collect();
+        boundaries.sort();
+        boundaries.dedup();
+
+        boundaries
+            .windows(2)
+            .map(|strip| {
+                let width = strip[1] - strip[0];
+                let covered_rows: IntervalSet<T> = self
+                    .rects
+                    .iter()
+                    .filter(|rect| rect.cols.start <= strip[0] && strip[1] <= rect.cols.end())
+                    .map(|rect| rect.rows)
+                    .collect();
+                let height = covered_rows
+                    .iter()
+                    .fold(T::zero(), |acc, span| acc + span.len);
+                width * height
+            })
+            .fold(T::zero(), |acc, area| acc + area)
+    }
+}
+
+impl<T: Integer + Copy + Hash> FromIterator<Rect<T>> for RectSet<T> {
+    fn from_iter<I: IntoIterator<Item = Rect<T>>>(iter: I) -> Self {
+        let mut result = Self::new();
+        for rect in iter {
+            result.insert(rect);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect() {
+        let rect: Rect<i32> = Rect::from_corners(&Coord2D::new(-1, 2), &Coord2D::new(1, 5));
+        assert_eq!(rect.area(), 12);
+        assert_eq!(rect.top_left(), Coord2D::new(-1, 2));
+        assert_eq!(rect.bottom_right(), Some(Coord2D::new(1, 5)));
+        assert!(rect.contains(&Coord2D::new(0, 5)));
+        assert!(!rect.contains(&Coord2D::new(0, 6)));
+
+        let other = Rect::new(Span1D::new(1, 10), Span1D::new(0, 3));
+        assert_eq!(
+            rect.intersection(&other),
+            Some(Rect::new(Span1D::new(1, 1), Span1D::new(2, 1)))
+        );
+        assert_eq!(
+            rect.intersection(&Rect::new(Span1D::new(2, 1), Span1D::new(0, 9))),
+            None
+        );
+
+        assert_eq!(
+            Rect::from_corners(&Coord2D::new(0, 0), &Coord2D::new(1, 2))
+                .coords()
+                .collect::<Vec<_>>(),
+            vec![
+                Coord2D::new(0, 0),
+                Coord2D::new(0, 1),
+                Coord2D::new(0, 2),
+                Coord2D::new(1, 0),
+                Coord2D::new(1, 1),
+                Coord2D::new(1, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quadrants() {
+        let rect: Rect<usize> = Rect::new(Span1D::new(0, 7), Span1D::new(0, 11));
+        let [north_west, north_east, south_west, south_east] = rect.quadrants();
+        assert_eq!(north_west, Rect::new(Span1D::new(0, 3), Span1D::new(0, 5)));
+        assert_eq!(north_east, Rect::new(Span1D::new(0, 3), Span1D::new(6, 5)));
+        assert_eq!(south_west, Rect::new(Span1D::new(4, 3), Span1D::new(0, 5)));
+        assert_eq!(south_east, Rect::new(Span1D::new(4, 3), Span1D::new(6, 5)));
+
+        let even: Rect<usize> = Rect::new(Span1D::new(2, 4), Span1D::new(0, 2));
+        assert_eq!(
+            even.quadrants().iter().map(|q| q.area()).sum::<usize>(),
+            even.area()
+        );
+    }
+
+    #[test]
+    fn test_union_area() {
+        let set: RectSet<i64> = [
+            Rect::new(Span1D::new(0, 4), Span1D::new(0, 4)),
+            Rect::new(Span1D::new(2, 4), Span1D::new(2, 4)),
+            Rect::new(Span1D::new(10, 1), Span1D::new(10, 1)),
+            Rect::new(Span1D::new(1, 1), Span1D::new(1, 1)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.union_area(), 16 + 16 - 4 + 1);
+        assert!(set.contains(&Coord2D::new(5, 5)));
+        assert!(!set.contains(&Coord2D::new(5, 0)));
+        assert_eq!(RectSet::<i64>::new().union_area(), 0);
+    }
+}
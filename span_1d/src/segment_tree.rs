@@ -0,0 +1,280 @@
+use std::marker::PhantomData;
+
+use num::{Bounded, Integer, NumCast, ToPrimitive};
+
+use crate::Span1D;
+
+/// How a `SegmentTree` combines values and applies range updates
+pub trait SegmentOp {
+    type Value: Copy;
+    type Update: Copy;
+
+    /// The result of querying an empty range
+    fn identity() -> Self::Value;
+
+    fn combine(left: Self::Value, right: Self::Value) -> Self::Value;
+
+    /// The combined value of len positions after applying update to each
+    fn apply(update: Self::Update, value: Self::Value, len: usize) -> Self::Value;
+
+    /// A single update equivalent to applying older, then newer
+    fn compose(newer: Self::Update, older: Self::Update) -> Self::Update;
+}
+
+/// Range sums, with updates that add to every value in a range
+pub struct RangeAddSum<V>(PhantomData<V>);
+
+impl<V: Integer + Copy + NumCast> SegmentOp for RangeAddSum<V> {
+    type Value = V;
+    type Update = V;
+
+    fn identity() -> V {
+        V::zero()
+    }
+
+    fn combine(left: V, right: V) -> V {
+        left + right
+    }
+
+    fn apply(update: V, value: V, len: usize) -> V {
+        let len: V = NumCast::from(len).expect("range length doesn't fit in the value type");
+        value + update * len
+    }
+
+    fn compose(newer: V, older: V) -> V {
+        newer + older
+    }
+}
+
+/// Range minimums, with updates that add to every value in a range
+pub struct RangeAddMin<V>(PhantomData<V>);
+
+impl<V: Integer + Copy + Bounded> SegmentOp for RangeAddMin<V> {
+    type Value = V;
+    type Update = V;
+
+    fn identity() -> V {
+        V::max_value()
+    }
+
+    fn combine(left: V, right: V) -> V {
+        std::cmp::min(left, right)
+    }
+
+    fn apply(update: V, value: V, _len: usize) -> V {
+        value + update
+    }
+
+    fn compose(newer: V, older: V) -> V {
+        newer + older
+    }
+}
+
+/// Range maximums, with updates that add to every value in a range
+pub struct RangeAddMax<V>(PhantomData<V>);
+
+impl<V: Integer + Copy + Bounded> SegmentOp for RangeAddMax<V> {
+    type Value = V;
+    type Update = V;
+
+    fn identity() -> V {
+        V::min_value()
+    }
+
+    fn combine(left: V, right: V) -> V {
+        std::cmp::max(left, right)
+    }
+
+    fn apply(update: V, value: V, _len: usize) -> V {
+        value + update
+    }
+
+    fn compose(newer: V, older: V) -> V {
+        newer + older
+    }
+}
+
+/// A value at every position of a span, with range queries and range
+/// updates in O(log n) time (updates are pushed down lazily)
+///
+/// ```
+/// use span_1d::{RangeAddSum, SegmentTree, Span1D};
+///
+/// let mut tree: SegmentTree<i32, RangeAddSum<i64>> = SegmentTree::new(-2, vec![1, 2, 3, 4]);
+/// tree.update(&Span1D::new(-1, 2), 10);
+/// assert_eq!(tree.query(&Span1D::new(-2, 3)), 26);
+/// ```
+pub struct SegmentTree<T: Integer + Copy + ToPrimitive, O: SegmentOp> {
+    domain: Span1D<T>,
+    n: usize,
+    values: Vec<O::Value>,
+    pending: Vec<Option<O::Update>>,
+}
+
+impl<T: Integer + Copy + ToPrimitive + NumCast, O: SegmentOp> SegmentTree<T, O> {
+    /// A tree whose positions start at start, one for each value
+    pub fn new(start: T, values: Vec<O::Value>) -> Self {
+        let n = values.len();
+        let len: T = NumCast::from(n).expect("too many values for the position type");
+        let mut tree = Self {
+            domain: Span1D::new(start, len),
+            n,
+            values: vec![O::identity(); 4 * n.max(1)],
+            pending: vec![None; 4 * n.max(1)],
+        };
+        if n > 0 {
+            tree.build(&values, 1, 0, n);
+        }
+        tree
+    }
+
+    /// The positions covered by the tree
+    pub fn domain(&self) -> Span1D<T> {
+        self.domain
+    }
+
+    fn build(&mut self, values: &[O::Value], node: usize, lo: usize, hi: usize) {
+        if hi - lo == 1 {
+            self.values[node] = values[lo];
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.build(values, 2 * node, lo, mid);
+        self.build(values, 2 * node + 1, mid, hi);
+        self.values[node] = O::combine(self.values[2 * node], self.values[2 * node + 1]);
+    }
+
+    /// The part of span inside the domain, as indices into the values
+    fn to_indices(&self, span: &Span1D<T>) -> Option<(usize, usize)> {
+        let span = span.intersection(&self.domain)?;
+        let lo = (span.start - self.domain.start).to_usize()?;
+        Some((lo, lo + span.len.to_usize()?))
+    }
+
+    fn apply_to_node(&mut self, node: usize, update: O::Update, len: usize) {
+        self.values[node] = O::apply(update, self.values[node], len);
+        self.pending[node] = Some(match self.pending[node] {
+            Some(older) => O::compose(update, older),
+            None => update,
+        });
+    }
+
+    fn push_down(&mut self, node: usize, lo: usize, hi: usize) {
+        if let Some(update) = self.pending[node].take() {
+            let mid = (lo + hi) / 2;
+            self.apply_to_node(2 * node, update, mid - lo);
+            self.apply_to_node(2 * node + 1, update, hi - mid);
+        }
+    }
+
+    /// Combine the values at every position in span (clipped to the domain)
+    pub fn query(&mut self, span: &Span1D<T>) -> O::Value {
+        match self.to_indices(span) {
+            Some((lo, hi)) => self.query_node(1, 0, self.n, lo, hi),
+            None => O::identity(),
+        }
+    }
+
+    fn query_node(
+        &mut self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        q_lo: usize,
+        q_hi: usize,
+    ) -> O::Value {
+        if q_hi <= lo || hi <= q_lo {
+            return O::identity();
+        }
+        if q_lo <= lo && hi <= q_hi {
+            return self.values[node];
+        }
+        self.push_down(node, lo, hi);
+        let mid = (lo + hi) / 2;
+        O::combine(
+            self.query_node(2 * node, lo, mid, q_lo, q_hi),
+            self.query_node(2 * node + 1, mid, hi, q_lo, q_hi),
+        )
+    }
+
+    /// Apply update to every position in span (clipped to the domain)
+    pub fn update(&mut self, span: &Span1D<T>, update: O::Update) {
+        if let Some((lo, hi)) = self.to_indices(span) {
+            self.update_node(1, 0, self.n, lo, hi, update);
+        }
+    }
+
+    fn update_node(
+        &mut self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        q_lo: usize,
+        q_hi: usize,
+        update: O::Update,
+    ) {
+        if q_hi <= lo || hi <= q_lo {
+            return;
+        }
+        if q_lo <= lo && hi <= q_hi {
+            self.apply_to_node(node, update, hi - lo);
+            return;
+        }
+        self.push_down(node, lo, hi);
+        let mid = (lo + hi) / 2;
+        self.update_node(2 * node, lo, mid, q_lo, q_hi, update);
+        self.update_node(2 * node + 1, mid, hi, q_lo, q_hi, update);
+        self.values[node] = O::combine(self.values[2 * node], self.values[2 * node + 1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check a tree against a plain vector after a series of range additions
+    fn check<O: SegmentOp<Value = i64, Update = i64>>(naive_combine: fn(&[i64]) -> i64) {
+        let mut naive: Vec<i64> = (0..37).map(|i| (i * 7919) % 23 - 11).collect();
+        let mut tree: SegmentTree<i32, O> = SegmentTree::new(5, naive.clone());
+
+        for step in 0..60_i64 {
+            let lo = (step * 13) % 37;
+            let len = (step * 7) % 11;
+            let span = Span1D::new(lo as i32 + 5, len as i32);
+
+            if step % 3 == 0 {
+                for value in naive.iter_mut().skip(lo as usize).take(len as usize) {
+                    *value += step - 30;
+                }
+                tree.update(&span, step - 30);
+            }
+
+            let hi = std::cmp::min(lo + len, 37);
+            if lo < hi {
+                assert_eq!(
+                    tree.query(&span),
+                    naive_combine(&naive[lo as usize..hi as usize])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_segment_tree() {
+        check::<RangeAddSum<i64>>(|values| values.iter().sum());
+        check::<RangeAddMin<i64>>(|values| *values.iter().min().unwrap());
+        check::<RangeAddMax<i64>>(|values| *values.iter().max().unwrap());
+    }
+
+    #[test]
+    fn test_clipping() {
+        let mut tree: SegmentTree<usize, RangeAddSum<u32>> = SegmentTree::new(10, vec![1; 5]);
+        assert_eq!(tree.domain(), Span1D::new(10, 5));
+        tree.update(&Span1D::new(0, 12), 2);
+        assert_eq!(tree.query(&Span1D::new(0, 100)), 9);
+        assert_eq!(tree.query(&Span1D::new(20, 3)), 0);
+
+        let mut empty: SegmentTree<usize, RangeAddMin<u32>> = SegmentTree::new(0, vec![]);
+        assert_eq!(empty.query(&Span1D::new(0, 3)), u32::MAX);
+    }
+}